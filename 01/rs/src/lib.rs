@@ -0,0 +1,141 @@
+use common::debug;
+use common::parse::{self, ParseError};
+
+// The calibration value of a line is made of its first and its last digit.
+// Part one only counts the digits 0-9, part two digit words like "one" as well.
+// Words may overlap, "eightwo" starts with 8 and ends with 2, so the line is scanned
+// from the front for the first digit and from the back for the last one instead of being split into words.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Digits,
+    Words,
+}
+
+// the digit words with their values, read from lines like "one 1"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordTable {
+    words: Vec<(String, u8)>,
+}
+
+pub struct Document {
+    pub lines: Vec<String>,
+    table: WordTable,
+}
+
+const ENGLISH: &str = "one 1\ntwo 2\nthree 3\nfour 4\nfive 5\nsix 6\nseven 7\neight 8\nnine 9\n";
+
+
+pub fn parse(input: &str) -> Result<Document, ParseError> {
+    parse_with(input, WordTable::default())
+}
+
+pub fn parse_with(input: &str, table: WordTable) -> Result<Document, ParseError> {
+    if let Some(ln) = input.lines().find(|ln| table.first(ln, Mode::Words).is_none()) {
+        return Err(ParseError::new(ln, "line contains no digit"));
+    }
+    Ok(Document { lines: input.lines().map(str::to_string).collect(), table })
+}
+
+pub fn part1(document: &Document) -> u32 {
+    calibrate(document, Mode::Digits)
+}
+
+pub fn part2(document: &Document) -> u32 {
+    calibrate(document, Mode::Words)
+}
+
+// lines without a digit of the mode don't count, the words may be all there is
+pub fn calibrate(document: &Document, mode: Mode) -> u32 {
+    let mut sum: u32 = 0;
+    for ln in &document.lines {
+        match (document.table.first(ln, mode), document.table.last(ln, mode)) {
+            (Some(first), Some(last)) => {
+                debug!("values", "{ln} => {first}{last}");
+                sum += 10 * first as u32 + last as u32;
+            }
+            _ => debug!("values", "{ln} => no digit"),
+        }
+    }
+    sum
+}
+
+impl WordTable {
+    // one word per line followed by its digit, empty lines and lines starting with '#' are ignored
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for ln in text.lines().map(str::trim).filter(|ln| !ln.is_empty() && !ln.starts_with('#')) {
+            let (word, digit) = parse::split_once(ln, " ")?;
+            let digit = digit.trim();
+            match parse::number(digit)? {
+                value @ 0..=9 => words.push((word.to_string(), value)),
+                _ => return Err(ParseError::new(digit, "expected a single digit")),
+            }
+        }
+        Ok(Self { words })
+    }
+
+    pub fn first(&self, ln: &str, mode: Mode) -> Option<u8> {
+        ln.char_indices().find_map(|(i, _)| self.digit_at(&ln[i..], mode))
+    }
+
+    pub fn last(&self, ln: &str, mode: Mode) -> Option<u8> {
+        ln.char_indices().rev().find_map(|(i, _)| self.digit_at(&ln[i..], mode))
+    }
+
+    // the digit `text` starts with
+    fn digit_at(&self, text: &str, mode: Mode) -> Option<u8> {
+        if let Some(digit) = text.chars().next().and_then(|c| c.to_digit(10)) {
+            return Some(digit as u8);
+        }
+        match mode {
+            Mode::Digits => None,
+            Mode::Words => self.words.iter().find(|(word, _)| text.starts_with(word.as_str())).map(|&(_, value)| value),
+        }
+    }
+}
+
+impl Default for WordTable {
+    fn default() -> Self {
+        Self::parse(ENGLISH).unwrap()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, parse_with, Mode, WordTable};
+
+    #[test]
+    fn test_overlapping_words() {
+        let table = WordTable::default();
+        assert_eq!(table.first("eightwothree", Mode::Words), Some(8));
+        assert_eq!(table.last("xtwone3fouroneight", Mode::Words), Some(8));
+        // the bash script of part two reads 88 here
+        assert_eq!((table.first("jcb82eightwond", Mode::Words), table.last("jcb82eightwond", Mode::Words)), (Some(8), Some(2)));
+    }
+
+    #[test]
+    fn test_digits_only() {
+        let table = WordTable::default();
+        assert_eq!((table.first("two1nine", Mode::Digits), table.last("two1nine", Mode::Digits)), (Some(1), Some(1)));
+        assert_eq!(table.first("eightwothree", Mode::Digits), None);
+    }
+
+    #[test]
+    fn test_custom_table() {
+        let table = WordTable::parse("# German\nnull 0\neins 1\nfünf 5\n").unwrap();
+        assert_eq!((table.first("xfünfeinsnullx", Mode::Words), table.last("xfünfeinsnullx", Mode::Words)), (Some(5), Some(0)));
+        assert_eq!(table.first("one", Mode::Words), None);
+        let document = parse_with("zweifünf\n3null\n", table).unwrap();
+        assert_eq!(crate::part2(&document), 55 + 30);
+        assert!(WordTable::parse("ten 10").is_err());
+        assert!(WordTable::parse("one").is_err());
+    }
+
+    #[test]
+    fn test_line_without_digit() {
+        assert_eq!(parse("two1nine\nabc\n").err().map(|err| err.token), Some("abc".to_string()));
+        assert_eq!(crate::part1(&parse("1abc2\neightwo\n").unwrap()), 12);
+    }
+}
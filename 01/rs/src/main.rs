@@ -1,40 +1,37 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::io::BufRead;
+use std::cell::OnceCell;
+use std::path::{Path, PathBuf};
 
-use regex::{Regex, Captures};
+use rs_1::WordTable;
 
-fn main() {
-    let pattern_digit = r"(one|two|three|four|five|six|seven|eight|nine|[0-9])";
-    let re_first_digit = Regex::new(pattern_digit).unwrap();
-    let re_last_digit = Regex::new(&format!(r".*{pattern_digit}.*?$")).unwrap();
+const USAGE: &str = "<part> [--words PATH] [--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
 
-    let file = File::open("../../input").unwrap();
-    let reader = BufReader::new(file);
-    let mut sum: u32 = 0;
-    for line in reader.lines() {
-        let ln = line.unwrap();
-        let d1 = to_number(re_first_digit.captures(&ln).unwrap());
-        let d2 = to_number(re_last_digit.captures(&ln).unwrap());
-        sum += (10*d1 + d2) as u32;
-        println!("{d1}{d2}");
+fn main() {
+    // a file with other digit words, like "zero 0" or another language
+    let words = OnceCell::<PathBuf>::new();
+    let option = |arg: &str, args: &mut dyn Iterator<Item = String>| match arg {
+        "--words" => {
+            let path = args.next().ok_or("missing value for --words")?;
+            words.set(path.into()).map_err(|_| "--words can only be given once".to_string())?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    };
+    let (part, document) = common::input::parse_part_from_args_with(1, USAGE, option, |input| {
+        rs_1::parse_with(input, words.get().map_or_else(WordTable::default, |path| load(path)))
+    });
+    match part {
+        1 => println!("sum: {}", rs_1::part1(&document)),
+        _ => println!("sum: {}", rs_1::part2(&document)),
     }
-
-        println!("sum: {sum}");
 }
 
-fn to_number(caps: Captures) -> u8 {
-        let digit = caps.get(1).unwrap().as_str();
-        match digit {
-            "one"   => 1,
-            "two"   => 2,
-            "three" => 3,
-            "four"  => 4,
-            "five"  => 5,
-            "six"   => 6,
-            "seven" => 7,
-            "eight" => 8,
-            "nine"  => 9,
-            _ => digit.parse().unwrap(),
-        }
+fn load(path: &Path) -> WordTable {
+    let text = std::fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("failed to read '{}': {err}", path.display());
+        std::process::exit(1);
+    });
+    WordTable::parse(&text).unwrap_or_else(|err| {
+        eprintln!("{}", err.locate(&text).in_file(path));
+        std::process::exit(1);
+    })
 }
@@ -0,0 +1,36 @@
+use rs_2::BagArg;
+
+// Lists which games every bag given admits and which cubes keep it from the others,
+// followed by the smallest bag admitting all games. Without a bag the one of the puzzle is used.
+
+const USAGE: &str = "[--bag CUBES | --bag-file PATH]... [--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
+
+fn main() {
+    let mut bags = Vec::new();
+    let option = |arg: &str, args: &mut dyn Iterator<Item = String>| {
+        let Some(given) = BagArg::parse_arg(arg, args)? else {
+            return Ok(false);
+        };
+        bags.push(given);
+        Ok(true)
+    };
+    let games = common::input::parse_from_args_with(2, USAGE, option, rs_2::parse);
+    let bags = match bags.is_empty() {
+        true => vec![rs_2::default_bag()],
+        false => bags.iter().map(BagArg::load).collect::<Result<Vec<_>, _>>().unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }),
+    };
+
+    for bag in &bags {
+        let admission = rs_2::admit(&games, bag);
+        println!("bag {bag}:");
+        let ids = admission.feasible.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        println!("  {} feasible games, sum of ids {}: {}", ids.len(), admission.feasible.iter().sum::<u32>(), ids.join(" "));
+        for (id, excess) in &admission.blocked {
+            println!("  game {id} blocked by {excess}");
+        }
+    }
+    println!("smallest bag admitting all games: {}", rs_2::smallest_bag(&games));
+}
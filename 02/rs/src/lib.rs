@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use common::parse::{self, ParseError};
+
+// the bag of the puzzle, other bags can be given on the command line
+pub const BAG: &str = "12 red, 13 green, 14 blue";
+
+// numbers of cubes by colour, like a handful drawn from the bag or the bag itself
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+pub struct Game {
+    id: u32,
+    sets: Vec<Cubes>,
+}
+
+// a bag given on the command line with --bag or --bag-file
+pub enum BagArg {
+    // like "12 red, 13 green, 14 blue"
+    Cubes(String),
+    File(PathBuf),
+}
+
+// which games could have been played with a bag
+#[derive(Debug, PartialEq, Eq)]
+pub struct Admission {
+    pub feasible: Vec<u32>,
+    // the ids of the other games with the cubes the bag lacks
+    pub blocked: Vec<(u32, Cubes)>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(Game::parse).collect()
+}
+
+pub fn part1(games: &[Game]) -> u32 {
+    feasible_sum(games, &default_bag())
+}
+
+pub fn part2(games: &[Game]) -> u64 {
+    power_sum(games, &default_bag())
+}
+
+pub fn default_bag() -> Cubes {
+    Cubes::parse(BAG).unwrap()
+}
+
+// the sum of the ids of the games which could have been played with `bag`
+pub fn feasible_sum(games: &[Game], bag: &Cubes) -> u32 {
+    games.iter().filter(|game| game.max_per_colour().fits_in(bag)).map(|game| game.id).sum()
+}
+
+// the sum of the powers of the smallest bags each game could have been played with,
+// the colours of `bag` count even if a game never shows them
+pub fn power_sum(games: &[Game], bag: &Cubes) -> u64 {
+    games.iter().map(|game| game.max_per_colour().power(bag.colours())).sum()
+}
+
+pub fn admit(games: &[Game], bag: &Cubes) -> Admission {
+    let mut out = Admission { feasible: Vec::new(), blocked: Vec::new() };
+    for game in games {
+        let excess = game.max_per_colour().excess(bag);
+        match excess.counts.is_empty() {
+            true => out.feasible.push(game.id),
+            false => out.blocked.push((game.id, excess)),
+        }
+    }
+    out
+}
+
+// the smallest bag every game could have been played with
+pub fn smallest_bag(games: &[Game]) -> Cubes {
+    let mut out = Cubes::default();
+    for game in games {
+        out.add_max(&game.max_per_colour());
+    }
+    out
+}
+
+impl Game {
+    fn parse(ln: &str) -> Result<Game, ParseError> {
+        let (name, sets) = parse::split_once(ln, ": ")?;
+
+        let name = parse::strip_prefix(name, "Game ")?;
+        let id: u32 = parse::number(name)?;
+
+        Ok(Game{id, sets: Self::parse_sets(sets)?})
+    }
+
+    fn parse_sets(sets: &str) -> Result<Vec<Cubes>, ParseError> {
+        sets.split("; ").map(Cubes::parse).collect()
+    }
+
+    // the smallest bag the game could have been played with
+    fn max_per_colour(&self) -> Cubes {
+        let mut out = Cubes::default();
+        for set in &self.sets {
+            out.add_max(set);
+        }
+        out
+    }
+}
+
+impl Cubes {
+    // "3 blue, 4 red", commas and line breaks separate the colours
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut counts = BTreeMap::new();
+        for cubes in text.split([',', '\n']).map(str::trim).filter(|cubes| !cubes.is_empty()) {
+            let (number, colour) = parse::split_once(cubes, " ")?;
+            let colour = colour.trim();
+            if counts.insert(colour.to_string(), parse::number(number)?).is_some() {
+                return Err(ParseError::new(colour, "colour given twice"));
+            }
+        }
+        Ok(Self { counts })
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    // colours which aren't in the bag don't fit at all
+    fn fits_in(&self, bag: &Cubes) -> bool {
+        self.excess(bag).counts.is_empty()
+    }
+
+    // the colours of which there are more than in the bag, with the number there are
+    fn excess(&self, bag: &Cubes) -> Cubes {
+        let counts = self.counts.iter().filter(|&(colour, &count)| count > bag.get(colour)).map(|(colour, &count)| (colour.clone(), count));
+        Cubes { counts: counts.collect() }
+    }
+
+    fn add_max(&mut self, other: &Cubes) {
+        for (colour, &count) in &other.counts {
+            let max = self.counts.entry(colour.clone()).or_default();
+            *max = count.max(*max);
+        }
+    }
+
+    // the product of the counts of all colours there are and of `others`, which are 0 if missing
+    fn power<'a>(&self, others: impl Iterator<Item = &'a str>) -> u64 {
+        let missing = others.filter(|colour| !self.counts.contains_key(*colour)).count();
+        match missing {
+            0 => self.counts.values().map(|&count| count as u64).product(),
+            _ => 0,
+        }
+    }
+}
+
+impl BagArg {
+    // consumes the value of `arg` from `args` if `arg` is --bag or --bag-file
+    pub fn parse_arg(arg: &str, args: &mut dyn Iterator<Item = String>) -> Result<Option<Self>, String> {
+        match arg {
+            "--bag" => Ok(Some(Self::Cubes(args.next().ok_or("missing value for --bag")?))),
+            "--bag-file" => Ok(Some(Self::File(args.next().ok_or("missing value for --bag-file")?.into()))),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn load(&self) -> Result<Cubes, String> {
+        let (text, origin) = match self {
+            Self::Cubes(cubes) => (cubes.clone(), Path::new("--bag")),
+            Self::File(path) => (std::fs::read_to_string(path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?, path.as_path()),
+        };
+        Cubes::parse(&text).map_err(|err| err.locate(&text).in_file(origin).to_string())
+    }
+}
+
+// the same way they are written in the input
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes = self.counts.iter().map(|(colour, count)| format!("{count} {colour}")).collect::<Vec<_>>();
+        f.write_str(&cubes.join(", "))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{admit, default_bag, feasible_sum, parse, power_sum, smallest_bag, Cubes};
+
+    #[test]
+    fn test_other_colours() {
+        let games = parse("Game 1: 3 blue, 4 yellow; 1 red\nGame 2: 1 red, 2 green, 6 blue\n").unwrap();
+        assert_eq!(feasible_sum(&games, &default_bag()), 2);
+        assert_eq!(feasible_sum(&games, &Cubes::parse("12 red, 13 green, 14 blue, 4 yellow").unwrap()), 3);
+        // game 1 has no green cubes
+        assert_eq!(power_sum(&games, &default_bag()), 12);
+        assert_eq!(power_sum(&games, &Cubes::parse("1 red").unwrap()), 12 + 12);
+    }
+
+    #[test]
+    fn test_parse_bag() {
+        let bag = Cubes::parse("12 red\n13 green,\n14 blue\n").unwrap();
+        assert_eq!(bag, default_bag());
+        assert_eq!((bag.get("green"), bag.get("yellow")), (13, 0));
+        assert_eq!(Cubes::parse("1 red, 2 red").err().map(|err| err.token), Some("red".to_string()));
+        assert!(Cubes::parse("red").is_err());
+    }
+
+    #[test]
+    fn test_admit() {
+        let games = parse(&common::input::read_example(2, "exp")).unwrap();
+        let admission = admit(&games, &default_bag());
+        assert_eq!(admission.feasible, vec![1, 2, 5]);
+        let blocked = admission.blocked.iter().map(|(id, excess)| format!("{id}: {excess}")).collect::<Vec<_>>();
+        assert_eq!(blocked, vec!["3: 20 red", "4: 15 blue, 14 red"]);
+        assert_eq!(smallest_bag(&games).to_string(), "15 blue, 13 green, 20 red");
+        assert_eq!(admit(&games, &smallest_bag(&games)).feasible.len(), 5);
+    }
+}
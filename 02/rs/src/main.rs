@@ -0,0 +1,25 @@
+use rs_2::BagArg;
+
+const USAGE: &str = "<part> [--bag CUBES | --bag-file PATH] [--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
+
+fn main() {
+    let mut bag = None;
+    let option = |arg: &str, args: &mut dyn Iterator<Item = String>| {
+        let Some(given) = BagArg::parse_arg(arg, args)? else {
+            return Ok(false);
+        };
+        match bag.replace(given) {
+            Some(_) => Err("--bag and --bag-file can only be given once".to_string()),
+            None => Ok(true),
+        }
+    };
+    let (part, games) = common::input::parse_part_from_args_with(2, USAGE, option, rs_2::parse);
+    let bag = bag.map_or(Ok(rs_2::default_bag()), |bag| bag.load()).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(1);
+    });
+    match part {
+        1 => println!("result: {}", rs_2::feasible_sum(&games, &bag)),
+        _ => println!("result: {}", rs_2::power_sum(&games, &bag)),
+    }
+}
@@ -0,0 +1,19 @@
+use rs_3::Format;
+
+// Prints the schematic with the part numbers, the ignored numbers and the gears with their numbers highlighted,
+// with ANSI colours for the terminal or as HTML page with --html, followed by the ratios of the gears.
+
+const USAGE: &str = "[--html] [--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
+
+fn main() {
+    let mut format = Format::Ansi;
+    let option = |arg: &str, _: &mut dyn Iterator<Item = String>| match arg {
+        "--html" => {
+            format = Format::Html;
+            Ok(true)
+        }
+        _ => Ok(false),
+    };
+    let engine_plan = common::input::parse_from_args_with(3, USAGE, option, rs_3::parse);
+    print!("{}", rs_3::render(&engine_plan, format));
+}
@@ -0,0 +1,315 @@
+use common::parse::{self, ParseError};
+use grid::{Cor, Grid};
+
+// The schematic is parsed into a grid and indexed by which numbers and symbols touch each other,
+// also diagonally, so a symbol may have any number of adjacent numbers and a number any number of symbols.
+// Part numbers and gears are queries on that index.
+// For debugging the schematic can be rendered with the part numbers, the ignored numbers and the gears highlighted.
+
+struct Number {
+    value: u32,
+    row: usize,
+    // the columns of the digits, the end is exclusive
+    start: usize,
+    end: usize,
+}
+
+struct Symbol {
+    symbol: char,
+    cor: Cor,
+}
+
+pub struct EnginePlan {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    // indices into `numbers` and `symbols`, both sorted
+    numbers_of_symbol: Vec<Vec<usize>>,
+    symbols_of_number: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Ansi,
+    Html,
+}
+
+// how a tile of the rendered schematic is highlighted
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Highlight {
+    Plain,
+    Part,
+    Ignored,
+    // a gear or one of its two numbers
+    Gear,
+}
+
+const EMPTY: char = '.';
+
+const HTML_HEAD: &str = "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Day 3 schematic</title>\n<style>\n\
+    .part { color: green; }\n.ignored { color: red; }\n.gear { color: darkorange; font-weight: bold; }\n</style>\n</head>\n<body>\n<pre>\n";
+const HTML_FOOT: &str = "</pre>\n</body>\n</html>\n";
+
+pub fn parse(input: &str) -> Result<EnginePlan, ParseError> {
+    EnginePlan::parse(input)
+}
+
+pub fn part1(engine_plan: &EnginePlan) -> u32 {
+    engine_plan.sum_part_numbers()
+}
+
+pub fn part2(engine_plan: &EnginePlan) -> u32 {
+    engine_plan.gears().map(|(_, ratio)| ratio).sum()
+}
+
+// the schematic with the numbers and gears highlighted, followed by a legend with the ratio of every gear
+pub fn render(engine_plan: &EnginePlan, format: Format) -> String {
+    let highlights = engine_plan.highlights();
+    let mut out = String::new();
+    if format == Format::Html {
+        out += HTML_HEAD;
+    }
+    for row in 0..engine_plan.grid.rows() {
+        let mut current = Highlight::Plain;
+        for (col, &c) in engine_plan.grid.row(row).iter().enumerate() {
+            let highlight = highlights[Cor::new(row, col)];
+            if highlight != current {
+                out += format.end(current);
+                out += format.start(highlight);
+                current = highlight;
+            }
+            format.push(&mut out, c);
+        }
+        out += format.end(current);
+        out.push('\n');
+    }
+
+    out.push('\n');
+    let legend = [(Highlight::Part, "part number"), (Highlight::Ignored, "ignored number"), (Highlight::Gear, "gear and its numbers")]
+        .map(|(highlight, text)| format!("{}{text}{}", format.start(highlight), format.end(highlight)));
+    out += &legend.join("  ");
+    out.push('\n');
+    for (i, ratio) in engine_plan.gears() {
+        let Symbol { symbol, cor } = &engine_plan.symbols[i];
+        let [a, b] = [0, 1].map(|n| engine_plan.numbers[engine_plan.numbers_of_symbol[i][n]].value);
+        let gear = format!("{}{symbol}{}", format.start(Highlight::Gear), format.end(Highlight::Gear));
+        out += &format!("{gear} at line {}, column {}: {a} * {b} = {ratio}\n", cor.row + 1, cor.col + 1);
+    }
+    out += &format!("sum of part numbers: {}, sum of gear ratios: {}\n", part1(engine_plan), part2(engine_plan));
+    if format == Format::Html {
+        out += HTML_FOOT;
+    }
+    out
+}
+
+impl Number {
+    fn cors(&self) -> impl Iterator<Item = Cor> + '_ {
+        (self.start..self.end).map(|col| Cor::new(self.row, col))
+    }
+}
+
+impl EnginePlan {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(input, Some)?;
+        let mut numbers = Vec::new();
+        for (row, ln) in input.lines().enumerate() {
+            let mut start = None;
+            // the digits of the line followed by the end of the line, which ends the last number
+            for (col, (i, c)) in ln.char_indices().chain([(ln.len(), EMPTY)]).enumerate() {
+                match (c.is_ascii_digit(), start) {
+                    (true, None) => start = Some((col, i)),
+                    (false, Some((start_col, start_i))) => {
+                        numbers.push(Number { value: parse::number(&ln[start_i..i])?, row, start: start_col, end: col });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let symbols = grid.iter()
+            .filter(|(_, &c)| c != EMPTY && !c.is_ascii_digit())
+            .map(|(cor, &symbol)| Symbol { symbol, cor })
+            .collect::<Vec<_>>();
+
+        let mut number_at = grid.map(|_| None);
+        for (i, number) in numbers.iter().enumerate() {
+            for cor in number.cors() {
+                number_at[cor] = Some(i);
+            }
+        }
+        let mut numbers_of_symbol = Vec::new();
+        let mut symbols_of_number = vec![Vec::new(); numbers.len()];
+        for (i, symbol) in symbols.iter().enumerate() {
+            let mut adjacent = grid.neighbours8(symbol.cor).filter_map(|cor| number_at[cor]).collect::<Vec<_>>();
+            adjacent.sort();
+            adjacent.dedup();
+            for &number in &adjacent {
+                symbols_of_number[number].push(i);
+            }
+            numbers_of_symbol.push(adjacent);
+        }
+        Ok(Self { grid, numbers, symbols, numbers_of_symbol, symbols_of_number })
+    }
+
+    // the numbers adjacent to at least one symbol
+    fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().zip(&self.symbols_of_number).filter(|(_, symbols)| !symbols.is_empty()).map(|(number, _)| number)
+    }
+
+    fn sum_part_numbers(&self) -> u32 {
+        self.part_numbers().map(|number| number.value).sum()
+    }
+
+    // the indices of the '*' symbols with exactly two adjacent numbers together with their ratio
+    fn gears(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        self.symbols.iter().zip(&self.numbers_of_symbol).enumerate().filter_map(|(i, (symbol, numbers))| match numbers[..] {
+            [a, b] if symbol.symbol == '*' => Some((i, self.numbers[a].value * self.numbers[b].value)),
+            _ => None,
+        })
+    }
+
+    fn highlights(&self) -> Grid<Highlight> {
+        let mut out = self.grid.map(|_| Highlight::Plain);
+        for (number, symbols) in self.numbers.iter().zip(&self.symbols_of_number) {
+            let highlight = if symbols.is_empty() { Highlight::Ignored } else { Highlight::Part };
+            number.cors().for_each(|cor| out[cor] = highlight);
+        }
+        for (i, _) in self.gears() {
+            out[self.symbols[i].cor] = Highlight::Gear;
+            for &number in &self.numbers_of_symbol[i] {
+                self.numbers[number].cors().for_each(|cor| out[cor] = Highlight::Gear);
+            }
+        }
+        out
+    }
+}
+
+impl Format {
+    fn start(self, highlight: Highlight) -> &'static str {
+        match (self, highlight) {
+            (_, Highlight::Plain) => "",
+            (Format::Ansi, Highlight::Part) => "\x1b[32m",
+            (Format::Ansi, Highlight::Ignored) => "\x1b[31m",
+            (Format::Ansi, Highlight::Gear) => "\x1b[1;33m",
+            (Format::Html, Highlight::Part) => "<span class=\"part\">",
+            (Format::Html, Highlight::Ignored) => "<span class=\"ignored\">",
+            (Format::Html, Highlight::Gear) => "<span class=\"gear\">",
+        }
+    }
+
+    fn end(self, highlight: Highlight) -> &'static str {
+        match (self, highlight) {
+            (_, Highlight::Plain) => "",
+            (Format::Ansi, _) => "\x1b[0m",
+            (Format::Html, _) => "</span>",
+        }
+    }
+
+    // symbols like '&' need to be escaped in HTML
+    fn push(self, out: &mut String, c: char) {
+        match (self, c) {
+            (Format::Html, '&') => *out += "&amp;",
+            (Format::Html, '<') => *out += "&lt;",
+            (Format::Html, '>') => *out += "&gt;",
+            _ => out.push(c),
+        }
+    }
+}
+
+
+
+// ========== tests ==========
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::{render, EnginePlan, Format};
+
+    // whether 42 in the columns 2 and 3 of the second row is a part number with a symbol in the given column above
+    fn is_adjacent(column: usize) -> bool {
+        let above = (0..6).map(|col| if col == column { '*' } else { '.' }).collect::<String>();
+        EnginePlan::parse(&format!("{above}\n..42..")).unwrap().sum_part_numbers() == 42
+    }
+
+    #[test]
+    fn test_not_adjacent_symbol_left () {
+        assert!(!is_adjacent(0));
+    }
+
+    #[test]
+    fn test_adjacent_symbol_diagonal_left () {
+        assert!(is_adjacent(1));
+    }
+
+    #[test]
+    fn test_adjacent_symbol_above_start () {
+        assert!(is_adjacent(2));
+    }
+
+    #[test]
+    fn test_adjacent_symbol_above_end () {
+        assert!(is_adjacent(3));
+    }
+
+    #[test]
+    fn test_adjacent_symbol_diagonal_right () {
+        assert!(is_adjacent(4));
+    }
+
+    #[test]
+    fn test_not_adjacent_symbol_right () {
+        assert!(!is_adjacent(5));
+    }
+
+
+    #[test]
+    fn test__engine_plan__not_adjacent_symbol_left () {
+        let p = EnginePlan::parse("*.42...").unwrap();
+        assert_eq!(p.sum_part_numbers(), 0);
+    }
+
+    #[test]
+    fn test__engine_plan__adjacent_symbol_left () {
+        let p = EnginePlan::parse("*42...").unwrap();
+        assert_eq!(p.sum_part_numbers(), 42);
+    }
+
+    #[test]
+    fn test__engine_plan__adjacent_symbol_right () {
+        let p = EnginePlan::parse(".42*..").unwrap();
+        assert_eq!(p.sum_part_numbers(), 42);
+    }
+
+    #[test]
+    fn test__engine_plan__not_adjacent_symbol_right () {
+        let p = EnginePlan::parse(".42.*..").unwrap();
+        assert_eq!(p.sum_part_numbers(), 0);
+    }
+
+    #[test]
+    fn test__engine_plan__any_number_of_neighbours () {
+        let p = EnginePlan::parse("1.2\n.*.\n3.4\n5*6").unwrap();
+        assert_eq!(p.numbers_of_symbol, vec![vec![0, 1, 2, 3], vec![2, 3, 4, 5]]);
+        assert_eq!(p.symbols_of_number[2], vec![0, 1]);
+        // touching two symbols counts once, and neither symbol is a gear
+        assert_eq!(p.sum_part_numbers(), 21);
+        assert_eq!(p.gears().count(), 0);
+    }
+
+    #[test]
+    fn test__engine_plan__gear () {
+        let p = EnginePlan::parse("467..114..\n...*......\n..35..633.").unwrap();
+        assert_eq!(p.gears().collect::<Vec<_>>(), vec![(0, 467 * 35)]);
+    }
+
+    #[test]
+    fn test_render () {
+        let p = EnginePlan::parse("467..1\n...*.&\n..35..\n").unwrap();
+        let ansi = render(&p, Format::Ansi);
+        assert!(ansi.starts_with("\x1b[1;33m467\x1b[0m..\x1b[32m1\x1b[0m\n...\x1b[1;33m*\x1b[0m.&\n..\x1b[1;33m35\x1b[0m..\n\n"));
+        assert!(ansi.contains("\x1b[1;33m*\x1b[0m at line 2, column 4: 467 * 35 = 16345\n"));
+        let html = render(&EnginePlan::parse("1.&\n").unwrap(), Format::Html);
+        assert!(html.contains("<pre>\n<span class=\"ignored\">1</span>.&amp;\n"));
+        assert!(html.ends_with("sum of part numbers: 0, sum of gear ratios: 0\n</pre>\n</body>\n</html>\n"));
+    }
+}
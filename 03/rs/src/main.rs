@@ -0,0 +1,7 @@
+fn main() {
+    let (part, engine_plan) = common::input::parse_part_from_args(3, rs_3::parse);
+    match part {
+        1 => println!("sum of part numbers: {}", rs_3::part1(&engine_plan)),
+        _ => println!("sum of gear ratios: {}", rs_3::part2(&engine_plan)),
+    }
+}
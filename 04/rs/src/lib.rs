@@ -1,10 +1,10 @@
 use std::fmt;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
+use common::parse::{self, ParseError};
+use common::trace;
 
-struct Card {
+
+pub struct Card {
     name: String,
     winning_numbers: Vec<u8>,
     drawn_numbers: Vec<u8>,
@@ -14,37 +14,45 @@ struct Copies {
     copies: Vec<u32>,
 }
 
-fn main() {
-    let filename = "../../input";
-    let file = File::open(filename).unwrap_or_else(|_| panic!("input file '{filename}' does not exist"));
-    let reader = BufReader::new(file);
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.lines().map(Card::parse).collect()
+}
+
+pub fn part1(cards: &[Card]) -> u32 {
+    cards.iter().map(|card| card.get_points()).sum()
+}
+
+pub fn part2(cards: &[Card]) -> u32 {
     let mut number_scratch_cards: u32 = 0;
     let mut copies = Copies::new();
-    for ln in reader.lines() {
-        let card = Card::parse(&ln.unwrap());
+    for card in cards {
         let n = card.count_winning_numbers();
         for _ in 0..copies.pop_copies() {
             number_scratch_cards += 1;
             copies.add_copies(n);
-            //println!("{card} => {n}");
-            //println!("        copies: {0:?}", copies.copies);
+            trace!("copies", "{card} => {n}");
+            trace!("copies", "        copies: {0:?}", copies.copies);
         }
     }
-    println!("number of scratch cards: {number_scratch_cards}");
+    number_scratch_cards
 }
 
 impl Card {
-    fn parse(ln: &str) -> Self {
-        let (name, numbers) = ln.split_once(": ").expect("missing separator ': '");
-        let (winning_numbers, drawn_numbers) = numbers.split_once(" | ").expect("missing separator ' | '");
-        Self {
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        let (name, numbers) = parse::split_once(ln, ": ")?;
+        let (winning_numbers, drawn_numbers) = parse::split_once(numbers, " | ")?;
+        Ok(Self {
             name: name.to_string(),
-            winning_numbers: Self::parse_numbers(winning_numbers),
-            drawn_numbers: Self::parse_numbers(drawn_numbers),
-        }
+            winning_numbers: Self::parse_numbers(winning_numbers)?,
+            drawn_numbers: Self::parse_numbers(drawn_numbers)?,
+        })
     }
-    fn parse_numbers(numbers: &str) -> Vec<u8> {
-        numbers.trim().replace("  ", " ").split(' ').map(|n| n.parse().expect("failed to parse number")).collect()
+    fn parse_numbers(numbers: &str) -> Result<Vec<u8>, ParseError> {
+        numbers.split_whitespace().map(parse::number).collect()
+    }
+
+    fn get_points(&self) -> u32 {
+        (1 << self.count_winning_numbers()) >> 1
     }
 
     fn count_winning_numbers(&self) -> u8 {
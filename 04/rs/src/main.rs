@@ -0,0 +1,7 @@
+fn main() {
+    let (part, cards) = common::input::parse_part_from_args(4, rs_4::parse);
+    match part {
+        1 => println!("sum of winning points: {}", rs_4::part1(&cards)),
+        _ => println!("number of scratch cards: {}", rs_4::part2(&cards)),
+    }
+}
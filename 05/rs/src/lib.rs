@@ -0,0 +1,114 @@
+use common::debug;
+use common::parse::{self, ParseError};
+use common::progress;
+
+struct MapEntry {
+    dst: u64,
+    src: u64,
+    len: u64,
+}
+
+struct Map {
+    entries: Vec<MapEntry>,
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut iterator = input.lines();
+    let seeds = parse_start_seeds(iterator.next().ok_or_else(|| ParseError::missing(input, "file is empty"))?)?;
+    let mut maps = Vec::<Map>::new();
+
+    for ln in iterator {
+        if ln.is_empty() {
+            // ignore empty lines
+        } else if ln.ends_with(':') {
+            // start new map
+            maps.push(Map::new());
+        } else {
+            let map = maps.last_mut().ok_or_else(|| ParseError::new(ln, "map entry before first map start"))?;
+            map.entries.push(MapEntry::parse(ln)?);
+        }
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+// like parse for part two, which reads the seeds as pairs of range start and length
+pub fn parse_ranges(input: &str) -> Result<Almanac, ParseError> {
+    let almanac = parse(input)?;
+    if almanac.seeds.len() % 2 == 1 {
+        let last = input.lines().next().and_then(|ln| ln.rsplit(' ').next()).unwrap_or(input);
+        return Err(ParseError::new(last, "odd number of seeds, the last one has no range length"));
+    }
+    Ok(almanac)
+}
+
+pub fn part1(almanac: &Almanac) -> u64 {
+    almanac.seeds.iter().map(|&seed| almanac.lookup(seed)).min().unwrap_or(u64::MAX)
+}
+
+//WARNING: don't run this code. It takes hours to find the correct solution like this.
+// the seeds are pairs of range start and length in part two
+pub fn part2(almanac: &Almanac) -> u64 {
+    debug!("seeds", "start_seeds: {:?}", almanac.seeds);
+
+    let mut min_location = u64::MAX;
+    // parse_ranges makes sure the number of seeds is even
+    for range in almanac.seeds.chunks_exact(2) {
+        let (start, len) = (range[0], range[1]);
+        for seed in start..start+len {
+            let location = almanac.lookup(seed);
+            if location < min_location {
+                min_location = location;
+                progress::candidate(min_location, "found new nearer location");
+            }
+        }
+    }
+    min_location
+}
+
+fn parse_start_seeds(ln: &str) -> Result<Vec<u64>, ParseError> {
+    let (_, seeds) = parse::split_once(ln, ": ")?;
+    seeds.split(' ').map(parse::number).collect()
+}
+
+impl Almanac {
+    fn lookup(&self, seed: u64) -> u64 {
+        let mut result = seed;
+        for map in &self.maps {
+            result = map.lookup(result);
+        }
+        result
+    }
+}
+
+impl MapEntry {
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        let mut s = ln.split(' ');
+        let mut next = |name| s.next().ok_or_else(|| ParseError::missing(ln, format!("missing {name}"))).and_then(parse::number);
+        let dst = next("destination range start")?;
+        let src = next("source range start")?;
+        let len = next("range length")?;
+        if let Some(value) = s.next() {
+            return Err(ParseError::new(value, "unexpected value after range length"));
+        }
+        Ok(Self {dst, src, len})
+    }
+}
+
+impl Map {
+    fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+    fn lookup(&self, val: u64) -> u64 {
+        for entry in &self.entries {
+            if entry.src <= val && val < entry.src + entry.len {
+                return val - entry.src + entry.dst;
+            }
+        }
+        val
+    }
+}
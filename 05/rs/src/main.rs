@@ -0,0 +1,10 @@
+fn main() {
+    let (part, almanac) = common::input::parse_part_from_args_by(5, |part, input| match part {
+        1 => rs_5::parse(input),
+        _ => rs_5::parse_ranges(input),
+    });
+    match part {
+        1 => println!("min_location: {}", rs_5::part1(&almanac)),
+        _ => println!("min_location: {}", rs_5::part2(&almanac)),
+    }
+}
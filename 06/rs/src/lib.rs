@@ -0,0 +1,73 @@
+use common::parse::{self, ParseError};
+
+struct Record {
+    time: u64,
+    distance: u64,
+}
+
+pub struct Records {
+    records: Vec<Record>,
+    // the race of part two
+    joined: Record,
+}
+
+pub fn parse(input: &str) -> Result<Records, ParseError> {
+    Records::parse(input)
+}
+
+pub fn part1(records: &Records) -> u64 {
+    let mut result: u64 = 1;
+
+    for r in &records.records {
+        result *= r.count_ways_to_win();
+    }
+    result
+}
+
+// there is only one race, the spaces between the numbers have to be ignored
+pub fn part2(records: &Records) -> u64 {
+    records.joined.count_ways_to_win()
+}
+
+fn calc_range_in_mm(time_race_in_ms: u64, time_press_button_in_ms: u64) -> u64 {
+    let time_driving_in_ms = time_race_in_ms - time_press_button_in_ms;
+    let speed_in_mm_per_ms = time_press_button_in_ms;
+    speed_in_mm_per_ms * time_driving_in_ms
+}
+
+impl Record {
+    fn count_ways_to_win(&self) -> u64 {
+        let mut number_of_possibilities_that_would_beat_the_highscore = 0;
+        for time_press_button_in_ms in 1..self.time {
+            if calc_range_in_mm(self.time, time_press_button_in_ms) > self.distance {
+                number_of_possibilities_that_would_beat_the_highscore += 1;
+            }
+        }
+        number_of_possibilities_that_would_beat_the_highscore
+    }
+}
+
+impl Records {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let times = lines.next().ok_or_else(|| ParseError::missing(input, "missing line with the times"))?;
+        let distances = lines.next().ok_or_else(|| ParseError::missing(input, "missing line with the distances"))?;
+        let (times, time) = Self::parse_line(times, "Time: ")?;
+        let (distances, distance) = Self::parse_line(distances, "Distance: ")?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::missing(input, "different number of times and distances given"));
+        }
+
+        let records = (0..times.len()).map(|i| Record{time: times[i], distance: distances[i]}).collect();
+        Ok(Self { records, joined: Record { time, distance } })
+    }
+
+    // the numbers of the line and the one number they make with the spaces ignored
+    fn parse_line(ln: &str, prefix: &str) -> Result<(Vec<u64>, u64), ParseError> {
+        let numbers = parse::strip_prefix(ln, prefix)?;
+        let values = numbers.split_whitespace().map(parse::number).collect::<Result<_, _>>()?;
+        let joined = numbers.split_whitespace().collect::<String>().parse().map_err(|_| ParseError::new(numbers.trim(), "the numbers joined are too large"))?;
+        Ok((values, joined))
+    }
+}
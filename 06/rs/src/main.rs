@@ -0,0 +1,7 @@
+fn main() {
+    let (part, records) = common::input::parse_part_from_args(6, rs_6::parse);
+    match part {
+        1 => println!("result: {}", rs_6::part1(&records)),
+        _ => println!("result: {}", rs_6::part2(&records)),
+    }
+}
@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::cmp::Ordering;
 use std::fmt;
 
+use common::parse::{self, ParseError};
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 struct Card {
     symbol: char,
@@ -21,66 +20,87 @@ enum Type {
     FiveOfAKind,
 }
 
+// part two turns the jacks into jokers which are the weakest card on their own
+// but act like whatever card would make the hand strongest
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Rules {
+    Standard,
+    Jokers,
+}
+
 #[derive(PartialEq, Eq)]
 struct Hand {
     cards: [Card; 5],
+    rules: Rules,
 }
 
-struct HandAndBid {
+pub struct HandAndBid {
     hand: Hand,
     bid: u32,
 }
 
 
-fn main() {
-    let file = File::open("../../input").expect("input file does not exist");
-    let reader = BufReader::new(file);
-    let mut hands_and_bids = Vec::new();
-    for ln in reader.lines().map(|ln| ln.unwrap()) {
-        hands_and_bids.push(HandAndBid::parse(&ln));
-    }
-    hands_and_bids.sort_by(|a,b| a.hand.cmp(&b.hand));
+pub fn parse(input: &str) -> Result<Vec<HandAndBid>, ParseError> {
+    input.lines().map(HandAndBid::parse).collect()
+}
+
+pub fn part1(hands_and_bids: &[HandAndBid]) -> u32 {
+    total_winnings(hands_and_bids, Rules::Standard)
+}
+
+pub fn part2(hands_and_bids: &[HandAndBid]) -> u32 {
+    total_winnings(hands_and_bids, Rules::Jokers)
+}
+
+fn total_winnings(hands_and_bids: &[HandAndBid], rules: Rules) -> u32 {
+    let mut hands_and_bids = hands_and_bids.iter().map(|item| (item.hand.with_rules(rules), item.bid)).collect::<Vec<_>>();
+    hands_and_bids.sort_by(|a,b| a.0.cmp(&b.0));
     let mut result: u32 = 0;
-    for (index, item) in hands_and_bids.into_iter().enumerate() {
+    for (index, (_, bid)) in hands_and_bids.into_iter().enumerate() {
         let rank = index + 1;
-        let bid = item.bid;
-        let win = rank as u32 * bid;
-        println!("{}: {rank} x {bid} = {win}", item.hand);
-        result += win;
+        result += rank as u32 * bid;
     }
-    println!("result = {result}");
+    result
 }
 
 
 impl HandAndBid {
-    fn parse(ln: &str) -> Self {
-        let (hand, bid) = ln.split_once(' ').expect("Invalid input line, should contain a space to separate hand and bid");
-        Self { hand: Hand::parse(hand), bid: bid.parse().expect("Failed to parse bid") }
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        let (hand, bid) = parse::split_once(ln, " ")?;
+        Ok(Self { hand: Hand::parse(hand, Rules::Standard)?, bid: parse::number(bid)? })
     }
 }
 
 impl Hand {
-    fn parse(cards: &str) -> Self {
-        if cards.len() != 5 {
-            panic!("Invalid hand '{cards}', should be 5 cards exactly");
+    fn parse(cards: &str, rules: Rules) -> Result<Self, ParseError> {
+        if cards.chars().count() != 5 {
+            return Err(ParseError::new(cards, "a hand has to be 5 cards exactly"));
         }
-        let mut cards = cards.chars();
-        Self {
+        let mut parsed = parse::chars(cards).map(|(symbol, token)| Card::parse(symbol, rules).ok_or_else(|| ParseError::new(token, "unknown card")));
+        Ok(Self {
             cards: [
-                Card::parse(cards.next().unwrap()),
-                Card::parse(cards.next().unwrap()),
-                Card::parse(cards.next().unwrap()),
-                Card::parse(cards.next().unwrap()),
-                Card::parse(cards.next().unwrap()),
+                parsed.next().unwrap()?,
+                parsed.next().unwrap()?,
+                parsed.next().unwrap()?,
+                parsed.next().unwrap()?,
+                parsed.next().unwrap()?,
             ],
-        }
+            rules,
+        })
+    }
+
+    fn with_rules(&self, rules: Rules) -> Self {
+        Self::parse(&self.to_string(), rules).expect("the cards are the same for all rules")
     }
 
     fn get_type(&self) -> Type {
         let mut found_pair: Option<Card> = None;
         let mut found_three: Option<Card> = None;
-        let joker = Card::parse('J');
-        let contains_joker = self.cards.contains(&joker);
+        let joker = match self.rules {
+            Rules::Standard => None,
+            Rules::Jokers => Card::parse('J', Rules::Jokers),
+        };
+        let contains_joker = joker.is_some_and(|joker| self.cards.contains(&joker));
         for card in self.cards {
             match self.cards.iter().filter(|&&c| c == card).count() {
                 5 => {
@@ -116,7 +136,7 @@ impl Hand {
                     }
                     if let Some(c) = found_pair {
                         if c != card {
-                            if card == joker || c == joker {
+                            if Some(card) == joker || Some(c) == joker {
                                 return Type::FourOfAKind;
                             } else if contains_joker {
                                 return Type::FullHouse;
@@ -165,7 +185,7 @@ impl Ord for Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
@@ -176,14 +196,15 @@ impl fmt::Display for Hand {
 }
 
 impl Card {
-    const SYMBOLS: &str = "J23456789TQKA";
-
-    fn parse(symbol: char) -> Self {
-        if let Some(index) = Self::SYMBOLS.find(symbol) {
-            Self { symbol, index }
-        } else {
-            panic!("unknown card '{symbol}'");
-        }
+    const SYMBOLS: &str = "23456789TJQKA";
+    const SYMBOLS_WITH_JOKERS: &str = "J23456789TQKA";
+
+    fn parse(symbol: char, rules: Rules) -> Option<Self> {
+        let symbols = match rules {
+            Rules::Standard => Self::SYMBOLS,
+            Rules::Jokers => Self::SYMBOLS_WITH_JOKERS,
+        };
+        symbols.find(symbol).map(|index| Self { symbol, index })
     }
 }
 
@@ -195,26 +216,35 @@ impl Ord for Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
 
-
+#[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
-    use crate::{Hand, Type};
+    use crate::{parse, Hand, Rules, Type};
+
+    fn standard(cards: &str) -> Hand {
+        Hand::parse(cards, Rules::Standard).unwrap()
+    }
+
+    fn jokers(cards: &str) -> Hand {
+        Hand::parse(cards, Rules::Jokers).unwrap()
+    }
 
     macro_rules! assert_type {
         ($input: expr, $expected_type: expr) => {
-            let actual_type = Hand::parse($input).get_type();
+            let actual_type = jokers($input).get_type();
             assert_eq!(actual_type, $expected_type, "\n input: \"{}\"\n", $input);
         }
     }
 
     macro_rules! assert_cmp {
         ($hand1: expr, $op: tt, $hand2: expr) => {
-            let hand1 = Hand::parse($hand1);
-            let hand2 = Hand::parse($hand2);
+            let hand1 = jokers($hand1);
+            let hand2 = jokers($hand2);
             assert!(hand1 $op hand2, "FAILED: {hand1} {} {hand2}", stringify!($op));
         }
     }
@@ -224,27 +254,27 @@ mod tests {
 
     #[test]
     fn test_type_32T3K () {
-        assert_eq!(Hand::parse("32T3K").get_type(), Type::OnePair);
+        assert_eq!(standard("32T3K").get_type(), Type::OnePair);
     }
 
     #[test]
     fn test_type_KK677 () {
-        assert_eq!(Hand::parse("KK677").get_type(), Type::TwoPair);
+        assert_eq!(standard("KK677").get_type(), Type::TwoPair);
     }
 
     #[test]
     fn test_type_KTJJT () {
-        assert_eq!(Hand::parse("KTJJT").get_type(), Type::FourOfAKind);
+        assert_eq!(standard("KTJJT").get_type(), Type::TwoPair);
     }
 
     #[test]
     fn test_type_T55J5 () {
-        assert_eq!(Hand::parse("T55J5").get_type(), Type::FourOfAKind);
+        assert_eq!(standard("T55J5").get_type(), Type::ThreeOfAKind);
     }
 
     #[test]
     fn test_type_QQQJA () {
-        assert_eq!(Hand::parse("QQQJA").get_type(), Type::FourOfAKind);
+        assert_eq!(standard("QQQJA").get_type(), Type::ThreeOfAKind);
     }
 
 
@@ -252,42 +282,101 @@ mod tests {
 
     #[test]
     fn test_type_JQQQJ () {
-        assert_eq!(Hand::parse("JQQQJ").get_type(), Type::FiveOfAKind);
+        assert_eq!(standard("JQQQJ").get_type(), Type::FullHouse);
     }
 
     #[test]
-    fn test_type_JQQQK () {
-        assert_eq!(Hand::parse("JQQQK").get_type(), Type::FourOfAKind);
+    fn test_type_QQQQQ () {
+        assert_eq!(standard("QQQQQ").get_type(), Type::FiveOfAKind);
     }
 
     #[test]
-    fn test_type_QQQQQ () {
-        assert_eq!(Hand::parse("QQQQQ").get_type(), Type::FiveOfAKind);
+    fn test_type_23456 () {
+        assert_eq!(standard("23456").get_type(), Type::HighCard);
+    }
+
+
+    // ------- cmp Hand tests for example -------
+
+    #[test]
+    fn test_hand_cmp_T55J5_QQQJA () {
+        assert!(standard("T55J5") < standard("QQQJA"));
     }
 
     #[test]
-    fn test_type_23456 () {
-        assert_eq!(Hand::parse("23456").get_type(), Type::HighCard);
+    fn test_hand_cmp_KTJJT_KK677 () {
+        assert!(standard("KTJJT") < standard("KK677"));
+    }
+
+
+    // ------- type tests with jokers -------
+
+    #[test]
+    fn test_type_32T3K_with_jokers () {
+        assert_eq!(jokers("32T3K").get_type(), Type::OnePair);
+    }
+
+    #[test]
+    fn test_type_KK677_with_jokers () {
+        assert_eq!(jokers("KK677").get_type(), Type::TwoPair);
+    }
+
+    #[test]
+    fn test_type_KTJJT_with_jokers () {
+        assert_eq!(jokers("KTJJT").get_type(), Type::FourOfAKind);
+    }
+
+    #[test]
+    fn test_type_T55J5_with_jokers () {
+        assert_eq!(jokers("T55J5").get_type(), Type::FourOfAKind);
+    }
+
+    #[test]
+    fn test_type_QQQJA_with_jokers () {
+        assert_eq!(jokers("QQQJA").get_type(), Type::FourOfAKind);
+    }
+
+
+    // ------- type tests for other types with jokers -------
+
+    #[test]
+    fn test_type_JQQQJ_with_jokers () {
+        assert_eq!(jokers("JQQQJ").get_type(), Type::FiveOfAKind);
     }
 
     #[test]
-    fn test_type_2345J () {
-        assert_eq!(Hand::parse("2345J").get_type(), Type::OnePair);
+    fn test_type_JQQQK_with_jokers () {
+        assert_eq!(jokers("JQQQK").get_type(), Type::FourOfAKind);
     }
 
     #[test]
-    fn test_type_2245J () {
-        assert_eq!(Hand::parse("2245J").get_type(), Type::ThreeOfAKind);
+    fn test_type_QQQQQ_with_jokers () {
+        assert_eq!(jokers("QQQQQ").get_type(), Type::FiveOfAKind);
     }
 
     #[test]
-    fn test_type_2244J () {
-        assert_eq!(Hand::parse("2244J").get_type(), Type::FullHouse);
+    fn test_type_23456_with_jokers () {
+        assert_eq!(jokers("23456").get_type(), Type::HighCard);
     }
 
     #[test]
-    fn test_type_2444J () {
-        assert_eq!(Hand::parse("2444J").get_type(), Type::FourOfAKind);
+    fn test_type_2345J_with_jokers () {
+        assert_eq!(jokers("2345J").get_type(), Type::OnePair);
+    }
+
+    #[test]
+    fn test_type_2245J_with_jokers () {
+        assert_eq!(jokers("2245J").get_type(), Type::ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_type_2244J_with_jokers () {
+        assert_eq!(jokers("2244J").get_type(), Type::FullHouse);
+    }
+
+    #[test]
+    fn test_type_2444J_with_jokers () {
+        assert_eq!(jokers("2444J").get_type(), Type::FourOfAKind);
     }
 
     #[test]
@@ -376,13 +465,13 @@ mod tests {
     // ------- cmp Hand tests for example -------
 
     #[test]
-    fn test_hand_cmp_T55J5_QQQJA () {
-        assert!(Hand::parse("T55J5") < Hand::parse("QQQJA"));
+    fn test_hand_cmp_T55J5_QQQJA_with_jokers () {
+        assert!(jokers("T55J5") < jokers("QQQJA"));
     }
 
     #[test]
-    fn test_hand_cmp_KTJJT_KK677 () {
-        assert!(Hand::parse("KTJJT") > Hand::parse("KK677"));
+    fn test_hand_cmp_KTJJT_KK677_with_jokers () {
+        assert!(jokers("KTJJT") > jokers("KK677"));
     }
 
 
@@ -402,4 +491,20 @@ mod tests {
         assert_cmp!("JK222", >, "AK222");
         assert_cmp!("K2222", >, "JK222");
     }
+
+    // ------- parse errors -------
+
+    #[test]
+    fn test_parse_unknown_card () {
+        let input = "32T3K 765\nT55X5 684\n";
+        let err = parse(input).err().unwrap().locate(input);
+        assert_eq!(err.token, "X");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 4)));
+    }
+
+    #[test]
+    fn test_parse_missing_bid () {
+        let err = parse("32T3K 765\nT55J5").err().unwrap();
+        assert_eq!(err.message, "missing ' '");
+    }
 }
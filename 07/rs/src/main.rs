@@ -0,0 +1,7 @@
+fn main() {
+    let (part, hands_and_bids) = common::input::parse_part_from_args(7, rs_7::parse);
+    match part {
+        1 => println!("result = {}", rs_7::part1(&hands_and_bids)),
+        _ => println!("result = {}", rs_7::part2(&hands_and_bids)),
+    }
+}
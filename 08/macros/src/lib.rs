@@ -12,8 +12,10 @@ static RE_NODE_LINE: Lazy::<Regex> = Lazy::new(|| Regex::new(r"^(?<name>[A-Z1-9]
 
 #[proc_macro]
 pub fn definitions(_input: TokenStream) -> TokenStream {
-    // you need to replace the file name with an absolute path
-    let input = std::fs::read_to_string("../../input").expect("input file does not exist");
+    let input = match common::crypt::read(&common::input::resolve(8, common::input::INPUT)) {
+        Ok(input) => input,
+        Err(err) => return unavailable(&err.to_string()),
+    };
     let mut lines = input.lines();
     let directions = lines.next().unwrap().chars().map(|c| match c {
         'L' => quote!{Direction::Left},
@@ -47,6 +49,8 @@ pub fn definitions(_input: TokenStream) -> TokenStream {
     let start_nodes_len = start_nodes.len();
 
     quote!{
+        const INPUT_ERROR: Option<&str> = None;
+
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
         enum Direction {
             Left,
@@ -84,6 +88,44 @@ pub fn definitions(_input: TokenStream) -> TokenStream {
     }.into()
 }
 
+// without the input the crate still has to build, part2 reports the error instead
+fn unavailable(err: &str) -> TokenStream {
+    quote!{
+        const INPUT_ERROR: Option<&str> = Some(#err);
+
+        #[allow(dead_code)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        enum Direction {
+            Left,
+            Right,
+        }
+
+        #[allow(dead_code)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        enum Node {
+            Unavailable,
+        }
+
+        #[allow(dead_code)]
+        impl Node {
+            fn next(&self, _direction: Direction) -> Self {
+                *self
+            }
+
+            fn is_start(&self) -> bool {
+                false
+            }
+
+            fn is_goal(&self) -> bool {
+                false
+            }
+        }
+
+        const DIRECTIONS: [Direction; 0] = [];
+        const START_NODES: [Node; 0] = [];
+    }.into()
+}
+
 fn to_name(node: String) -> Ident {
     format_ident!("N_{}", node)
 }
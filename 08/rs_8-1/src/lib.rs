@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use common::parse::{self, ParseError};
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static RE_NODE_LINE: Lazy::<Regex> = Lazy::new(|| Regex::new(r"^(?<name>[A-Z]+)\s*=\s*\((?<left>[A-Z]+),\s*(?<right>[A-Z]+)\)$").unwrap());
+
+
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+struct Nodes {
+    nodes: HashMap<String, Node>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+#[derive(Clone)]
+struct Directions {
+    directions: Vec<Direction>,
+    index: usize,
+}
+
+
+pub struct Network {
+    directions: Directions,
+    nodes: Nodes,
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let mut lines = input.lines();
+    let directions = Directions::parse(lines.next().ok_or_else(|| ParseError::missing(input, "file is empty"))?)?;
+    let nodes = Nodes::parse(lines)?;
+    Ok(Network { directions, nodes })
+}
+
+pub fn part1(network: &Network) -> u32 {
+    let mut directions = network.directions.clone();
+    let mut node = network.nodes.get_start();
+    let mut count: u32 = 0;
+    while !node.is_goal() {
+        let direction = directions.next();
+        node = network.nodes.get_next(node, direction);
+        count += 1;
+    }
+    count
+}
+
+
+impl Direction {
+    fn parse(symbol: &str) -> Result<Self, ParseError> {
+        match symbol {
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::new(symbol, "unknown direction")),
+        }
+    }
+}
+
+impl Directions {
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        if ln.is_empty() {
+            return Err(ParseError::new(ln, "missing directions"));
+        }
+        Ok(Self {
+            index: 0,
+            directions: parse::chars(ln).map(|(_, symbol)| Direction::parse(symbol)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn next(&mut self) -> Direction {
+        let out = self.directions[self.index];
+        self.index += 1;
+        if self.index >= self.directions.len() {
+            self.index = 0;
+        }
+        out
+    }
+}
+
+impl Node {
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        let caps = RE_NODE_LINE.captures(ln).ok_or_else(|| ParseError::new(ln, "expected a node like 'AAA = (BBB, CCC)' but got"))?;
+        Ok(Node {
+            name: caps.name("name").unwrap().as_str().to_string(),
+            left: caps.name("left").unwrap().as_str().to_string(),
+            right: caps.name("right").unwrap().as_str().to_string(),
+        })
+    }
+
+    fn get_next(&self, direction: Direction) -> String {
+        match direction {
+            Direction::Left => self.left.to_string(),
+            Direction::Right => self.right.to_string(),
+        }
+    }
+
+    fn is_goal(&self) -> bool {
+        self.name == "ZZZ"
+    }
+}
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Nodes {
+    fn parse<'a>(lines: impl Iterator<Item=&'a str>) -> Result<Self, ParseError> {
+        Ok(Self { nodes: lines.filter(|ln| !ln.is_empty()).map(|ln| Node::parse(ln).map(|n| (n.name.to_string(), n))).collect::<Result<_, _>>()? })
+    }
+
+    fn get_start(&self) -> &Node {
+        &self.nodes["AAA"]
+    }
+
+    fn get_next(&self, current_node: &Node, direction: Direction) -> &Node {
+        &self.nodes[&current_node.get_next(direction)]
+    }
+}
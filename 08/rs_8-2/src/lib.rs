@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate macros;
+
+definitions!();
+
+//Warning: don't try to run this, it takes way too long and a u32 is not even big enough.
+// I guess I could try to loop over every node individually until I find a repetition in (index_direction, node)
+// and then only check the steps where the longest period has it's goals.
+
+// there is no parse since the nodes are compiled in by definitions!()
+pub fn part2() -> Result<u32, String> {
+    if let Some(err) = INPUT_ERROR {
+        return Err(err.to_string());
+    }
+    let mut nodes = START_NODES;
+    let mut step: u32 = 0;
+    let number_directions = DIRECTIONS.len();
+    while !nodes.iter().all(|n| n.is_goal()) {
+        let direction = DIRECTIONS[step as usize % number_directions];
+        for node in nodes.iter_mut() {
+            *node = node.next(direction);
+        }
+        step += 1;
+    }
+    Ok(step)
+}
@@ -0,0 +1,69 @@
+use std::ops::{Add,Sub};
+use std::fmt::Debug;
+
+use common::parse::{self, ParseError};
+use common::trace;
+
+pub struct Report {
+    histories: Vec<Vec<i32>>,
+}
+
+pub fn parse(input: &str) -> Result<Report, ParseError> {
+    Ok(Report {
+        histories: input.lines().map(|ln| ln.split_whitespace().map(parse::number).collect()).collect::<Result<_, _>>()?,
+    })
+}
+
+pub fn part1(report: &Report) -> i32 {
+    report.histories.iter().map(|data| predict(data)).sum()
+}
+
+pub fn part2(report: &Report) -> i32 {
+    report.histories.iter().map(|data| predict_past(data)).sum()
+}
+
+// the data followed by its differences until they are all zero
+fn differences<T>(data: &[T]) -> Vec<Vec<T>>
+where
+    T: Sub<Output=T> + PartialEq + Default + Copy,
+{
+    let mut diffs = Vec::new();
+    diffs.push(data.to_owned());
+    let zero = Default::default();
+    while !diffs.last().unwrap().iter().all(|item| *item == zero) {
+        diffs.push( diffs.last().unwrap().iter().zip(diffs.last().unwrap().iter().skip(1)).map(|(current, next)| *next - *current).collect() );
+    }
+    diffs
+}
+
+fn predict<T>(data: &[T]) -> T
+where
+    T: Add<Output=T> + Sub<Output=T> + PartialEq + Default + Copy + Debug,
+{
+    let mut diffs = differences(data);
+    let n = diffs.len();
+    let mut last: T = Default::default();
+    for i in (0..n).rev() {
+        let new = *diffs[i].last().unwrap() + last;
+        last = new;
+        diffs[i].push(last);
+        trace!("diffs", "    {:?}", diffs[i]);
+    }
+    *diffs.first().unwrap().last().unwrap()
+}
+
+fn predict_past<T>(data: &[T]) -> T
+where
+    T: Add<Output=T> + Sub<Output=T> + PartialEq + Default + Copy + Debug,
+{
+    let mut diffs = differences(data);
+    let n = diffs.len();
+    let mut last: T = Default::default();
+    for i in (0..n).rev() {
+        let new = *diffs[i].first().unwrap() - last;
+        last = new;
+        diffs[i].insert(0, last);
+        trace!("diffs", "    {:?}", diffs[i]);
+    }
+    *diffs.first().unwrap().first().unwrap()
+}
@@ -0,0 +1,7 @@
+fn main() {
+    let (part, report) = common::input::parse_part_from_args(9, rs_9::parse);
+    match part {
+        1 => println!("result: {}", rs_9::part1(&report)),
+        _ => println!("result: {}", rs_9::part2(&report)),
+    }
+}
@@ -0,0 +1,383 @@
+use std::fmt;
+
+use common::debug;
+use common::parse::{self, ParseError};
+use grid::{Cor, Direction, Grid, RelativeDirection};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Shape {
+    NS,
+    WE,
+    NE,
+    NW,
+    SW,
+    SE,
+}
+
+// the shape of the start field is only known after parsing and ground has no shape
+struct Field {
+    shape: Option<Shape>,
+    is_start: bool,
+    distance: Option<u32>,
+    is_inside: bool,
+}
+
+pub struct Map {
+    map: Grid<Field>,
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::parse(input)
+}
+
+pub fn part1(map: &Map) -> u32 {
+    map.map.tiles().filter_map(|f| f.distance).max().unwrap()
+}
+
+pub fn part2(map: &Map) -> usize {
+    map.map.tiles().filter(|f| f.is_inside).count()
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut map = Grid::<Field>::parse(input)?;
+        let start = map.position(|f| f.is_start).ok_or_else(|| ParseError::missing(input, "input did not contain a start field"))?;
+
+        let shape = Self::get_shape_from_neighbours(&map, start).ok_or_else(|| {
+            ParseError::new(Self::token(input, start), "start field does not have exactly two connecting neighbours")
+        })?;
+        map[start].shape = Some(shape);
+        Self::check_loop(&map, start, input)?;
+        Self::count_distances(&mut map, start);
+        Self::set_inside(&mut map, start);
+
+        let map = Self { map };
+        debug!("distances", "distances from the start:\n{}", map.render_distances());
+        Ok(map)
+    }
+
+    // follows the loop from the start, every pipe of it has to lead to one pointing back
+    fn check_loop(map: &Grid<Field>, start: Cor, input: &str) -> Result<(), ParseError> {
+        let mut cor = start;
+        let mut going_to = map[start].shape().to_directions().0;
+        loop {
+            let next = map.step(cor, going_to).ok_or_else(|| ParseError::new(Self::token(input, cor), "pipe leads outside of the map"))?;
+            if !map[next].is_pointing(going_to.opposite()) {
+                return Err(ParseError::new(Self::token(input, cor), "pipe leads to a field which doesn't connect back"));
+            }
+            if next == start {
+                return Ok(());
+            }
+            going_to = map[next].shape().to_other_direction(going_to.opposite());
+            cor = next;
+        }
+    }
+
+    // the field at `cor` in the input
+    fn token(input: &str, cor: Cor) -> &str {
+        input.lines().nth(cor.row).and_then(|ln| parse::chars(ln).nth(cor.col)).map_or(input, |(_, token)| token)
+    }
+
+    fn count_distances(map: &mut Grid<Field>, start: Cor) {
+        let mut distance = 0;
+        map[start].distance = Some(distance);
+        let mut c1 = start;
+        let mut c2 = start;
+        let (mut d1, mut d2) = map[start].shape().to_directions();
+        loop {
+            (c1, d1) = Self::get_next(map, c1, d1);
+            (c2, d2) = Self::get_next(map, c2, d2);
+            distance += 1;
+            if map[c1].distance.is_some() {
+                break;
+            }
+            map[c1].distance = Some(distance);
+            if map[c2].distance.is_some() {
+                break;
+            }
+            map[c2].distance = Some(distance);
+        }
+    }
+
+    fn set_inside(map: &mut Grid<Field>, start: Cor) {
+        let mut coming_from = map[start].shape().to_directions().0;
+        let inside = Self::get_inside_side(map, start, coming_from);
+        let mut cor_pipe = start;
+        loop {
+            (cor_pipe, coming_from) = Self::get_next(map, cor_pipe, coming_from);
+            if cor_pipe == start {
+                break;
+            }
+
+            let going_to = map[cor_pipe].shape().to_other_direction(coming_from);
+            let mut directions_pointing_inwards = Vec::new();
+            let turn = Self::get_turn(coming_from, going_to.opposite());
+            if turn == RelativeDirection::Straight {
+                directions_pointing_inwards.push(coming_from.opposite().turn(inside))
+            } else if turn == inside.opposite() {
+                directions_pointing_inwards.push(coming_from.opposite());
+                directions_pointing_inwards.push(coming_from.opposite().turn(inside));
+            };
+            for d in directions_pointing_inwards {
+                let mut cor_inner = cor_pipe;
+                loop {
+                    cor_inner = Self::step(map, cor_inner, d);
+                    let field = &mut map[cor_inner];
+                    if field.distance.is_some() {
+                        // I have hit the other side of the pipe, that's the end of the inside
+                        break;
+                    } else {
+                        field.is_inside = true;
+                    }
+                }
+            }
+        }
+    }
+    fn get_inside_side(map: &Grid<Field>, start: Cor, coming_from: Direction) -> RelativeDirection {
+        let mut left = 0;
+        let mut right = 0;
+        let mut d0 = coming_from;
+        let mut d1: Direction;
+        let mut c = start;
+        loop {
+            (c, d1) = Self::get_next(map, c, d0);
+            if c == start {
+                break;
+            }
+            match Self::get_turn(d0, d1) {
+                RelativeDirection::Left => {left += 1;}
+                RelativeDirection::Right => {right += 1;}
+                _ => {}
+            }
+            d0 = d1;
+        }
+        if left > right {
+            RelativeDirection::Left
+        } else {
+            RelativeDirection::Right
+        }
+    }
+    fn get_turn(coming_from_0: Direction, coming_from_1: Direction) -> RelativeDirection {
+        let turn = coming_from_0.opposite().relative(coming_from_1.opposite());
+        assert_ne!(turn, RelativeDirection::Back, "180° turn is not possible");
+        turn
+    }
+
+    fn get_next(map: &Grid<Field>, cor: Cor, coming_from: Direction) -> (Cor, Direction) {
+        let (d1, d2) = map[cor].shape().to_directions();
+        if d1 == coming_from {
+            (Self::step(map, cor, d2), d2.opposite())
+        } else {
+            (Self::step(map, cor, d1), d1.opposite())
+        }
+    }
+
+    // the loop has been checked while parsing, it never leaves the map
+    fn step(map: &Grid<Field>, cor: Cor, d: Direction) -> Cor {
+        map.step(cor, d).expect("pipe leads outside of the map")
+    }
+
+    fn get_shape_from_neighbours(map: &Grid<Field>, cor: Cor) -> Option<Shape> {
+        let mut directions = Vec::new();
+        for d in Direction::ALL {
+            if let Some(field) = map.step(cor, d).map(|neighbour| &map[neighbour]) {
+                if field.is_pointing(d.opposite()) {
+                    directions.push(d);
+                }
+            }
+        }
+        (directions.len() == 2).then(|| Shape::from_directions(directions[0], directions[1]))
+    }
+
+    fn render_distances(&self) -> String {
+        let mut out = String::new();
+        for row in self.map.iter_rows() {
+            for field in row {
+                if field.shape.is_some() {
+                    if let Some(distance) = field.distance {
+                        out += &distance.to_string();
+                    } else {
+                        out.push('?');
+                    }
+                } else {
+                    out.push(' ');
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for row in self.map.iter_rows() {
+            for field in row {
+                if field.is_inside {
+                    write!(f, "I")?;
+                } else if let Some(shape) = field.shape {
+                    write!(f, "{}", shape.to_symbol())?;
+                } else {
+                    write!(f, " ")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Shape {
+    fn parse(symbol: char) -> Option<Self> {
+        match symbol {
+            '|' => Some(Self::NS),
+            '-' => Some(Self::WE),
+            'L' => Some(Self::NE),
+            'J' => Some(Self::NW),
+            '7' => Some(Self::SW),
+            'F' => Some(Self::SE),
+            _ => None,
+        }
+    }
+
+    fn to_input_symbol(self) -> char {
+        match self {
+            Self::NS => '|',
+            Self::WE => '-',
+            Self::NE => 'L',
+            Self::NW => 'J',
+            Self::SW => '7',
+            Self::SE => 'F',
+        }
+    }
+
+    fn to_symbol(self) -> char {
+        match self {
+            Self::NS => '│',
+            Self::WE => '─',
+            Self::NE => '└',
+            Self::NW => '┘',
+            Self::SW => '┐',
+            Self::SE => '┌',
+        }
+    }
+
+    fn from_directions(d1: Direction, d2: Direction) -> Self {
+        match (d1, d2) {
+            (Direction::N, Direction::S) => Self::NS,
+            (Direction::S, Direction::N) => Self::NS,
+
+            (Direction::N, Direction::W) => Self::NW,
+            (Direction::W, Direction::N) => Self::NW,
+
+            (Direction::N, Direction::E) => Self::NE,
+            (Direction::E, Direction::N) => Self::NE,
+
+            (Direction::W, Direction::E) => Self::WE,
+            (Direction::E, Direction::W) => Self::WE,
+
+            (Direction::S, Direction::W) => Self::SW,
+            (Direction::W, Direction::S) => Self::SW,
+
+            (Direction::S, Direction::E) => Self::SE,
+            (Direction::E, Direction::S) => Self::SE,
+
+            (Direction::N, Direction::N) => panic!("two different directions must be given"),
+            (Direction::S, Direction::S) => panic!("two different directions must be given"),
+            (Direction::W, Direction::W) => panic!("two different directions must be given"),
+            (Direction::E, Direction::E) => panic!("two different directions must be given"),
+        }
+    }
+
+    fn to_directions(self) -> (Direction, Direction) {
+        match self {
+            Self::NS => (Direction::N, Direction::S),
+            Self::NW => (Direction::N, Direction::W),
+            Self::NE => (Direction::N, Direction::E),
+            Self::WE => (Direction::W, Direction::E),
+            Self::SW => (Direction::S, Direction::W),
+            Self::SE => (Direction::S, Direction::E),
+        }
+    }
+
+    fn to_other_direction(self, d: Direction) -> Direction {
+        match (self, d) {
+            (Self::NS, Direction::N) => Direction::S,
+            (Self::NS, Direction::S) => Direction::N,
+
+            (Self::NW, Direction::N) => Direction::W,
+            (Self::NW, Direction::W) => Direction::N,
+
+            (Self::NE, Direction::N) => Direction::E,
+            (Self::NE, Direction::E) => Direction::N,
+
+            (Self::WE, Direction::W) => Direction::E,
+            (Self::WE, Direction::E) => Direction::W,
+
+            (Self::SW, Direction::S) => Direction::W,
+            (Self::SW, Direction::W) => Direction::S,
+
+            (Self::SE, Direction::S) => Direction::E,
+            (Self::SE, Direction::E) => Direction::S,
+
+            (Self::NS, Direction::W) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::NS, Direction::E) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::WE, Direction::N) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::WE, Direction::S) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::NW, Direction::S) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::NW, Direction::E) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::SW, Direction::N) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::SW, Direction::E) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::SE, Direction::N) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::SE, Direction::W) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::NE, Direction::W) => panic!("{d:?} is not one of {self:?} directions"),
+            (Self::NE, Direction::S) => panic!("{d:?} is not one of {self:?} directions"),
+        }
+    }
+}
+
+impl grid::Tile for Field {
+    fn from_char(symbol: char) -> Option<Self> {
+        let (shape, is_start) = match symbol {
+            'S' => (None, true),
+            '.' => (None, false),
+            _ => (Some(Shape::parse(symbol)?), false),
+        };
+        Some(Self { shape, is_start, distance: None, is_inside: false })
+    }
+
+    fn to_char(&self) -> char {
+        match self.shape {
+            _ if self.is_start => 'S',
+            Some(shape) => shape.to_input_symbol(),
+            None => '.',
+        }
+    }
+}
+
+impl Field {
+    fn shape(&self) -> Shape {
+        self.shape.expect("field is not a pipe")
+    }
+
+    fn is_pointing_north(&self) -> bool {
+        matches!(self.shape, Some(Shape::NS | Shape::NW | Shape::NE))
+    }
+    fn is_pointing_south(&self) -> bool {
+        matches!(self.shape, Some(Shape::NS | Shape::SW | Shape::SE))
+    }
+    fn is_pointing_west(&self) -> bool {
+        matches!(self.shape, Some(Shape::WE | Shape::SW | Shape::NW))
+    }
+    fn is_pointing_east(&self) -> bool {
+        matches!(self.shape, Some(Shape::WE | Shape::SE | Shape::NE))
+    }
+    fn is_pointing(&self, d: Direction) -> bool {
+        match d {
+            Direction::N => self.is_pointing_north(),
+            Direction::S => self.is_pointing_south(),
+            Direction::W => self.is_pointing_west(),
+            Direction::E => self.is_pointing_east(),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+fn main() {
+    let (part, map) = common::input::parse_part_from_args(10, rs_10::parse);
+    match part {
+        1 => println!("result: {}", rs_10::part1(&map)),
+        _ => println!("Number fields inside: {}", rs_10::part2(&map)),
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use common::parse::{self, ParseError};
+use common::{debug, trace};
+
+#[derive(Clone)]
+pub struct Galaxies {
+    galaxies: Vec<Galaxy>,
+    rows: usize,
+    cols: usize,
+}
+
+#[derive(Clone)]
+struct Galaxy {
+    row: usize,
+    col: usize,
+}
+
+pub fn parse(input: &str) -> Result<Galaxies, ParseError> {
+    Galaxies::parse(input)
+}
+
+pub fn part1(galaxies: &Galaxies) -> usize {
+    sum_of_distances(galaxies, 2)
+}
+
+pub fn part2(galaxies: &Galaxies) -> usize {
+    sum_of_distances(galaxies, 1000000)
+}
+
+pub fn sum_of_distances(galaxies: &Galaxies, expansion: usize) -> usize {
+    let mut galaxies = galaxies.clone();
+    debug!("galaxies", "read galaxies:\n{}", galaxies);
+
+    galaxies.expand(expansion);
+    debug!("galaxies", "galaxies after expansion:\n{}", galaxies);
+
+    galaxies.get_distances().iter().sum()
+}
+
+impl Galaxies {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut galaxies = Vec::new();
+        let mut row = 0;
+        let mut col = 0;
+        for ln in input.lines() {
+            col = 0;
+            for (s, token) in parse::chars(ln) {
+                match s {
+                    '#' => galaxies.push( Galaxy { row, col } ),
+                    '.' => {}
+                    _ => return Err(ParseError::new(token, "unknown symbol")),
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+
+        Ok(Self {galaxies, rows: row, cols: col})
+    }
+
+    fn expand(&mut self, expansion: usize) {
+        let empty_rows = (0..self.rows).filter(|row| self.galaxies.iter().all(|g| g.row != *row)).collect::<Vec<usize>>();
+        let empty_cols = (0..self.cols).filter(|col| self.galaxies.iter().all(|g| g.col != *col)).collect::<Vec<usize>>();
+        debug!("expansion", "empty rows: {:?}", empty_rows);
+        debug!("expansion", "empty cols: {:?}", empty_cols);
+        let exp = expansion - 1;
+
+        for row in empty_rows.iter().rev() {
+            for galaxy in &mut self.galaxies {
+                if galaxy.row > *row {
+                    galaxy.row += exp;
+                }
+            }
+        }
+
+        for col in empty_cols.iter().rev() {
+            for galaxy in &mut self.galaxies {
+                if galaxy.col > *col {
+                    galaxy.col += exp;
+                }
+            }
+        }
+
+        self.rows += empty_rows.len() * exp;
+        self.cols += empty_cols.len() * exp;
+    }
+
+    fn get_distances(&self) -> Vec<usize> {
+        let mut out = Vec::new();
+        for i in 0..self.galaxies.len() {
+            for j in i+1..self.galaxies.len() {
+                let g1 = &self.galaxies[i];
+                let g2 = &self.galaxies[j];
+                let d = g1.get_distance(g2);
+                out.push(d);
+                trace!("distances", "distance between galaxy {} and {}: {d}", i+1, j+1);
+            }
+        }
+        out
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<usize> {
+        for (i, galaxy) in self.galaxies.iter().enumerate() {
+            if galaxy.row == row && galaxy.col == col {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for Galaxies {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                if let Some(galaxy) = self.get(row, col) {
+                    write!(f, "{}", galaxy+1)?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Galaxy {
+    fn get_distance(&self, other: &Galaxy) -> usize {
+        let d_row = other.row.abs_diff(self.row);
+        let d_col = other.col.abs_diff(self.col);
+        d_row + d_col
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, sum_of_distances};
+
+    #[test]
+    fn test_expansions_from_puzzle_text() {
+        let galaxies = parse(&common::input::read_example(11, "exp")).unwrap();
+        assert_eq!(sum_of_distances(&galaxies, 10), 1030);
+        assert_eq!(sum_of_distances(&galaxies, 100), 8410);
+    }
+}
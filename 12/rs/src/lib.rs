@@ -0,0 +1,62 @@
+use common::parse::{self, ParseError};
+use common::{debug, trace};
+use regex::Regex;
+
+pub struct Record {
+    broken: String,
+    checksum: Vec<usize>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    input.lines().map(|ln| {
+        let (broken, checksum) = parse::split_once(ln, " ")?;
+        if let Some((_, token)) = parse::chars(broken).find(|(c, _)| !matches!(c, '.' | '#' | '?')) {
+            return Err(ParseError::new(token, "invalid character in broken input"));
+        }
+        Ok(Record {
+            broken: broken.to_string(),
+            checksum: checksum.split(',').map(parse::number).collect::<Result<_, _>>()?,
+        })
+    }).collect()
+}
+
+pub fn part1(records: &[Record]) -> u32 {
+    let mut result = 0;
+    for record in records {
+        let re = Regex::new(&(r"^\.*".to_string() + &record.checksum.iter().map(|&n| "#".repeat(n)).collect::<Vec<_>>().join(r"\.+") + r"\.*$")).expect("failed to build regex");
+        let mut possibilites = 0;
+        for possibility in get_possible_fixes(&record.broken) {
+            trace!("fixes", "    {possibility}");
+            if re.is_match(&possibility) {
+                possibilites += 1;
+            }
+        }
+        debug!("records", "{} {:?}: {possibilites}", record.broken, record.checksum);
+        result += possibilites;
+    }
+    result
+}
+
+fn get_possible_fixes(broken: &str) -> Vec<String> {
+    let mut out = Vec::<String>::new();
+    out.push("".to_string());
+    for c in broken.chars() {
+        match c {
+            '.'|'#' => {
+                for fix in out.iter_mut() {
+                    *fix += &c.to_string();
+                }
+            }
+            '?' => {
+                for i in 0..out.len() {
+                    out.push(out[i].clone() + "#");
+                    out[i] += ".";
+                }
+            }
+            _ => {
+                panic!("invalid character '{c}' in broken input");
+            }
+        }
+    }
+    out
+}
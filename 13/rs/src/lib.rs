@@ -0,0 +1,101 @@
+use common::parse::ParseError;
+use grid::{Cor, Grid, Tile};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Orientation {
+    Row,
+    Col,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Terrain {
+    Ash,
+    Rock,
+}
+
+pub struct Map {
+    grid: Grid<Terrain>,
+}
+
+
+pub fn parse(input: &str) -> Result<Vec<Map>, ParseError> {
+    input.split("\n\n").map(|par| Ok(Map { grid: Grid::parse(par)? })).collect()
+}
+
+pub fn part1(maps: &[Map]) -> usize {
+    let mut sum_mirror_rows: usize = 0;
+    let mut sum_mirror_cols: usize = 0;
+
+    for map in maps {
+        sum_mirror_rows += map.get_mirrors(Orientation::Row).iter().sum::<usize>();
+        sum_mirror_cols += map.get_mirrors(Orientation::Col).iter().sum::<usize>();
+    }
+
+    sum_mirror_rows * 100 + sum_mirror_cols
+}
+
+
+impl Map {
+    fn get_mirrors(&self, orientation: Orientation) -> Vec<usize> {
+        let mut out = ( 1 .. self.size(orientation) ).collect::<Vec<_>>();
+        for series in 0..self.size(orientation.other()) {
+            self.check_mirrors(orientation, series, &mut out);
+        }
+        out
+    }
+    fn check_mirrors(&self, orientation: Orientation, series: usize, out: &mut Vec<usize>) {
+        for i in out.clone() {
+            if !self.is_mirror(orientation, series, i) {
+                out.remove(out.iter().position(|x| *x == i).unwrap());
+            }
+        }
+    }
+    fn is_mirror(&self, orientation: Orientation, series: usize, index: usize) -> bool {
+        for i in 1..*[index + 1, self.size(orientation)-index+1].iter().min().unwrap() {
+            if self.getr(orientation, series, index - i) != self.getr(orientation, series, index + i - 1) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn size(&self, orientation: Orientation) -> usize {
+        match orientation {
+            Orientation::Row => self.grid.rows(),
+            Orientation::Col => self.grid.cols(),
+        }
+    }
+    fn getr(&self, orientation: Orientation, series: usize, index: usize) -> Terrain {
+        let cor = match orientation {
+            Orientation::Row => Cor { row: index, col: series },
+            Orientation::Col => Cor { row: series, col: index },
+        };
+        self.grid[cor]
+    }
+}
+
+impl Tile for Terrain {
+    fn from_char(symbol: char) -> Option<Self> {
+        match symbol {
+            '#' => Some(Self::Rock),
+            '.' => Some(Self::Ash),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Rock => '#',
+            Self::Ash => '.',
+        }
+    }
+}
+
+impl Orientation {
+    fn other(&self) -> Self {
+        match self {
+            Self::Row => Self::Col,
+            Self::Col => Self::Row,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use common::debug;
+use common::parse::ParseError;
+use grid::{Cor, Grid, Tile};
+
+pub type ResultType = u32;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Rock {
+    Empty,
+    Round,
+    Cube,
+}
+
+#[derive(Clone)]
+pub struct Platform {
+    platform: Grid<Rock>,
+}
+
+
+pub fn parse(input: &str) -> Result<Platform, ParseError> {
+    Platform::parse(input)
+}
+
+pub fn part1(platform: &Platform) -> ResultType {
+    let mut platform = platform.clone();
+    debug!("platform", "read in:\n{}\n", platform);
+
+    platform.tilt_north();
+    debug!("platform", "tilted north:\n{}\n", platform);
+
+    platform.calc_weight_north()
+}
+
+
+impl Platform {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self { platform: Grid::parse(input)? })
+    }
+
+    fn tilt_north(&mut self) {
+        for col in 0..self.platform.cols() {
+            for row in 1..self.platform.rows() {
+                if self.platform[Cor{row, col}] == Rock::Round {
+                    let new_row = self.find_free_row_north(row, col);
+                    self.platform[Cor{row, col}] = Rock::Empty;
+                    self.platform[Cor{row: new_row, col}] = Rock::Round;
+                }
+            }
+        }
+    }
+
+    fn find_free_row_north(&self, row: usize, col: usize) -> usize {
+        for new_row in (0..row).rev() {
+            if self.platform[Cor{row: new_row, col}] != Rock::Empty {
+                return new_row + 1;
+            }
+        }
+        0
+    }
+
+    fn calc_weight_north(&self) -> ResultType {
+        let mut result: ResultType = 0;
+        for (cor, &rock) in self.platform.iter() {
+            if rock == Rock::Round {
+                result += (self.platform.rows() - cor.row) as ResultType;
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.platform)
+    }
+}
+
+impl Tile for Rock {
+    fn from_char(symbol: char) -> Option<Self> {
+        match symbol {
+            '.' => Some(Self::Empty),
+            'O' => Some(Self::Round),
+            '#' => Some(Self::Cube),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Round => 'O',
+            Self::Cube => '#',
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use common::parse::{self, ParseError};
+
 type FocalLength = u8;
 
 struct Lens {
@@ -14,30 +16,48 @@ enum Command {
     Remove(String),
 }
 
+// every step is a command, which only matters in part two
+pub struct Sequence {
+    steps: Vec<String>,
+    commands: Vec<Command>,
+}
+
+pub fn parse(input: &str) -> Result<Sequence, ParseError> {
+    let steps = input.trim_end().split(',').collect::<Vec<_>>();
+    Ok(Sequence {
+        commands: steps.iter().map(|step| Command::parse(step)).collect::<Result<_, _>>()?,
+        steps: steps.into_iter().map(str::to_string).collect(),
+    })
+}
+
+pub fn part1(sequence: &Sequence) -> u32 {
+    sequence.steps.iter().map(|step| hash(step) as u32).sum()
+}
 
-fn main() {
+// in part two every step is a command changing the lenses in the boxes
+pub fn part2(sequence: &Sequence) -> u64 {
     let mut boxes = (0..256).map(|_| Box::new()).collect::<Vec<_>>();
     let mut result: u64 = 0;
-    for cmd in std::fs::read_to_string("../../input").expect("input file not found").trim_end().split(',') {
-        Command::parse(cmd).exec(&mut boxes);
+    for cmd in &sequence.commands {
+        cmd.exec(&mut boxes);
     }
     for (box_number, r#box) in boxes.iter().enumerate() {
         for (slot_number, lens) in r#box.lenses.iter().enumerate() {
             result += (box_number + 1) as u64 * (slot_number + 1) as u64 * lens.focal_length as u64;
         }
     }
-    println!("result: {result}");
+    result
 }
 
 impl Command {
-    fn parse(cmd: &str) -> Self {
-        if cmd.ends_with('-') {
-            Self::Remove(cmd[0..(cmd.len()-1)].to_string())
+    fn parse(cmd: &str) -> Result<Self, ParseError> {
+        if let Some(label) = cmd.strip_suffix('-') {
+            Ok(Self::Remove(label.to_string()))
         } else {
-            let (label, focal_length) = cmd.split_once('=').expect("invalid command '{cmd}', is neither Remove nor Set");
+            let (label, focal_length) = cmd.split_once('=').ok_or_else(|| ParseError::new(cmd, "command is neither Remove nor Set"))?;
             let label = label.to_string();
-            let focal_length: FocalLength = focal_length.parse().expect("failed to parse focal length of lens");
-            Self::Set(label, focal_length)
+            let focal_length: FocalLength = parse::number(focal_length)?;
+            Ok(Self::Set(label, focal_length))
         }
     }
 
@@ -0,0 +1,7 @@
+fn main() {
+    let (part, sequence) = common::input::parse_part_from_args(15, rs_15::parse);
+    match part {
+        1 => println!("result: {}", rs_15::part1(&sequence)),
+        _ => println!("result: {}", rs_15::part2(&sequence)),
+    }
+}
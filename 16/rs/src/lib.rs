@@ -1,6 +1,8 @@
 use std::fmt;
 
-type CorType = u8;
+use common::debug;
+use common::parse::ParseError;
+use grid::{Cor, Direction};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Object {
@@ -11,26 +13,15 @@ enum Object {
     SplitterHor,
 }
 
+#[derive(Clone)]
 struct Tile {
     object: Object,
     beam_directions: Vec<Direction>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Direction {
-    N, S, W, E,
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-struct Cor {
-    row: CorType,
-    col: CorType,
-}
-
-struct Grid {
-    tiles: Vec<Vec<Tile>>,
-    rows: CorType,
-    cols: CorType,
+#[derive(Clone)]
+pub struct Grid {
+    tiles: grid::Grid<Tile>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -40,59 +31,54 @@ struct Beam {
 }
 
 
-fn main() {
-    let mut grid = Grid::read(std::path::Path::new("../../input"));
-    //println!("{}", grid);
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Ok(Grid { tiles: grid::Grid::parse(input)? })
+}
+
+pub fn part1(grid: &Grid) -> u32 {
+    let mut grid = grid.clone();
+    debug!("grid", "\n{}", grid);
 
     grid.energize(Beam::start());
-    //grid.print_beams();
-    //println!();
-    //grid.print_energized();
+    debug!("beams", "\n{}", grid.render_beams());
+    debug!("energized", "\n{}", grid.render_energized());
 
-    //println!();
-    println!("number energized fields: {}", grid.count_energized());
+    grid.count_energized()
 }
 
+pub fn part2(grid: &Grid) -> u32 {
+    debug!("grid", "\n{}", grid);
 
-impl Grid {
-    fn read(path: &std::path::Path) -> Self {
-        Self::parse(std::fs::read_to_string(path).unwrap_or_else(|_| panic!("no such file '{}'", path.display())).lines())
-    }
-    fn parse<'a>(lines: impl Iterator<Item = &'a str>) -> Self {
-        let mut tiles = Vec::new();
-        for ln in lines {
-            tiles.push(ln.chars().map(Tile::parse).collect::<Vec<_>>());
+    let mut result: u32 = 0;
+    for row in 0..grid.tiles.rows() {
+        for (col, direction) in [(0, Direction::W), (grid.tiles.cols()-1, Direction::E)] {
+            let n = grid.clone().energize(Beam::new(row, col, direction)).count_energized();
+            result = result.max(n);
         }
-
-        let cols = tiles[0].len();
-        for row in tiles.iter().skip(1) {
-            assert_eq!(row.len(), cols, "rows have inconsistent length");
+    }
+    for col in 0..grid.tiles.cols() {
+        for (row, direction) in [(0, Direction::S), (grid.tiles.rows()-1, Direction::N)] {
+            let n = grid.clone().energize(Beam::new(row, col, direction)).count_energized();
+            result = result.max(n);
         }
-        let rows: CorType = tiles.len().try_into().unwrap();
-        let cols: CorType = cols.try_into().unwrap();
-
-        Self { tiles, rows, cols }
     }
+    result
+}
+
 
-    fn energize(&mut self, beam: Beam) {
+impl Grid {
+    fn energize(&mut self, beam: Beam) -> &Self {
         self.energize_tile(&beam);
         let mut beams = vec![beam];
         while !beams.is_empty() {
             beams = beams.iter().flat_map(|b| self.move_beam(b)).collect();
         }
 
-    }
-
-    fn get(&self, cor: Cor) -> &Tile {
-        &self.tiles[cor.row as usize][cor.col as usize]
-    }
-
-    fn get_mut(&mut self, cor: Cor) -> &mut Tile {
-        &mut self.tiles[cor.row as usize][cor.col as usize]
+        self
     }
 
     fn move_beam(&mut self, beam: &Beam) -> Vec<Beam> {
-        let tile = self.get(beam.cor);
+        let tile = &self.tiles[beam.cor];
         let cor = beam.cor;
         let direction = beam.direction;
         let out: Vec<Beam> = match (tile.object, beam.direction) {
@@ -115,15 +101,15 @@ impl Grid {
 
             // splitter
             (Object::SplitterVer, Direction::E|Direction::W) => self.next_cor(cor, Direction::S).map(|cor| Beam{cor, direction: Direction::S}).into_iter().chain(
-                                                                self.next_cor(cor, Direction::N).map(|cor| Beam{cor, direction: Direction::N}).into_iter()).collect(),
+                                                                self.next_cor(cor, Direction::N).map(|cor| Beam{cor, direction: Direction::N})).collect(),
             (Object::SplitterHor, Direction::N|Direction::S) => self.next_cor(cor, Direction::E).map(|cor| Beam{cor, direction: Direction::E}).into_iter().chain(
-                                                                self.next_cor(cor, Direction::W).map(|cor| Beam{cor, direction: Direction::W}).into_iter()).collect(),
+                                                                self.next_cor(cor, Direction::W).map(|cor| Beam{cor, direction: Direction::W})).collect(),
         };
         out.into_iter().filter(|b| self.energize_tile(b)).collect()
     }
 
     fn energize_tile(&mut self, beam: &Beam) -> bool {
-        let tile = self.get_mut(beam.cor);
+        let tile = &mut self.tiles[beam.cor];
         if !tile.beam_directions.contains(&beam.direction) {
             tile.beam_directions.push(beam.direction);
             true
@@ -133,98 +119,78 @@ impl Grid {
     }
 
     fn next_cor(&self, cor: Cor, direction: Direction) -> Option<Cor> {
-        match direction {
-            Direction::N => if cor.row == 0 {
-                None
-            } else {
-                Some( Cor { row: cor.row-1, ..cor } )
-            },
-            Direction::W => if cor.col == 0 {
-                None
-            } else {
-                Some( Cor { col: cor.col-1, ..cor } )
-            },
-            Direction::S => if cor.row + 1 >= self.rows {
-                None
-            } else {
-                Some( Cor { row: cor.row+1, ..cor } )
-            },
-            Direction::E => if cor.col + 1 >= self.cols {
-                None
-            } else {
-                Some( Cor { col: cor.col+1, ..cor } )
-            },
-        }
+        self.tiles.step(cor, direction)
     }
 
     fn count_energized(&self) -> u32 {
-        let mut count: u32 = 0;
-        for row in &self.tiles {
-            for tile in row {
-                if tile.is_energized() {
-                    count += 1;
-                }
-            }
-        }
-        count
+        self.tiles.tiles().filter(|tile| tile.is_energized()).count() as u32
     }
 
-    fn print_energized(&self) {
-        for row in &self.tiles {
+    fn render_energized(&self) -> String {
+        let mut out = String::new();
+        for row in self.tiles.iter_rows() {
             for tile in row {
                 if tile.is_energized() {
-                    print!("#");
+                    out.push('#');
                 } else {
-                    print!(".");
+                    out.push('.');
                 }
             }
-            println!();
+            out.push('\n');
         }
+        out
     }
 
-    fn print_beams(&self) {
-        for row in &self.tiles {
+    fn render_beams(&self) -> String {
+        let mut out = String::new();
+        for row in self.tiles.iter_rows() {
             for tile in row {
-                print!("{}", tile.to_beam_symbol());
+                out.push(tile.to_beam_symbol());
             }
-            println!();
+            out.push('\n');
         }
+        out
     }
 }
 
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in &self.tiles {
-            for tile in row {
-                write!(f, "{}", tile.to_object_symbol())?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        write!(f, "{}", self.tiles)
     }
 }
 
-impl Tile {
-    fn parse(symbol: char) -> Self {
-        Self {
-            object: Object::parse(symbol),
+impl grid::Tile for Tile {
+    fn from_char(symbol: char) -> Option<Self> {
+        Some(Self {
+            object: Object::parse(symbol)?,
             beam_directions: Vec::new(),
-        }
+        })
     }
 
-    fn is_energized(&self) -> bool {
-        !self.beam_directions.is_empty()
+    fn to_char(&self) -> char {
+        self.object.to_symbol()
     }
+}
 
-    fn to_object_symbol(&self) -> char {
-        self.object.to_symbol()
+fn to_beam_symbol(direction: Direction) -> char {
+    match direction {
+        Direction::N => '^',
+        Direction::S => 'v',
+        Direction::W => '<',
+        Direction::E => '>',
+    }
+}
+
+impl Tile {
+    fn is_energized(&self) -> bool {
+        !self.beam_directions.is_empty()
     }
 
     fn to_beam_symbol(&self) -> char {
         if self.object != Object::Empty {
             self.object.to_symbol()
         } else if self.beam_directions.len() == 1 {
-            self.beam_directions[0].to_beam_symbol()
+            to_beam_symbol(self.beam_directions[0])
         } else if self.beam_directions.len() > 1 {
             self.beam_directions.len().to_string().chars().next().unwrap()
         } else {
@@ -233,26 +199,15 @@ impl Tile {
     }
 }
 
-impl Direction {
-    fn to_beam_symbol(self) -> char {
-        match self {
-            Self::N => '^',
-            Self::S => 'v',
-            Self::W => '<',
-            Self::E => '>',
-        }
-    }
-}
-
 impl Object {
-    fn parse(symbol: char) -> Self {
+    fn parse(symbol: char) -> Option<Self> {
         match symbol {
-            '.' => Object::Empty,
-            '/' => Object::MirrorSwNe,
-            '\\' => Object::MirrorNwSe,
-            '|' => Object::SplitterVer,
-            '-' => Object::SplitterHor,
-            _ => panic!("invalid tile '{symbol}'"),
+            '.' => Some(Object::Empty),
+            '/' => Some(Object::MirrorSwNe),
+            '\\' => Some(Object::MirrorNwSe),
+            '|' => Some(Object::SplitterVer),
+            '-' => Some(Object::SplitterHor),
+            _ => None,
         }
     }
 
@@ -270,14 +225,15 @@ impl Object {
 impl Beam {
     fn start() -> Self {
         Self {
-            cor: Cor::origin(),
+            cor: Cor::default(),
             direction: Direction::E,
         }
     }
-}
 
-impl Cor {
-    fn origin() -> Self {
-        Self { row: 0, col: 0 }
+    fn new(row: usize, col: usize, direction: Direction) -> Self {
+        Self {
+            direction,
+            cor: Cor { row, col },
+        }
     }
 }
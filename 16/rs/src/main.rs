@@ -0,0 +1,7 @@
+fn main() {
+    let (part, grid) = common::input::parse_part_from_args(16, rs_16::parse);
+    match part {
+        1 => println!("number energized fields: {}", rs_16::part1(&grid)),
+        _ => println!("result: {}", rs_16::part2(&grid)),
+    }
+}
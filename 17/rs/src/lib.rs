@@ -0,0 +1,159 @@
+//WARNING: this program takes 30 minutes to run
+
+use common::parse::ParseError;
+use common::{debug, progress, stats, trace};
+use grid::{Cor, Direction, Grid};
+
+type HeatLoss = u8;
+type CumulatedHeatLoss = u32;
+
+pub struct HeatLossMap {
+    map: Grid<HeatLoss>,
+}
+
+#[derive(Clone, Debug)]
+struct Step {
+    cor: Cor,
+    direction: Direction,
+    heat_loss: CumulatedHeatLoss,
+    same_direction_counter: u8,
+}
+
+struct Ways {
+    steps: Grid<Vec<Step>>,
+    // how many steps have been added and how many have been dropped because a known step was better
+    explored: u64,
+    discarded: u64,
+}
+
+
+pub fn parse(input: &str) -> Result<HeatLossMap, ParseError> {
+    HeatLossMap::parse(input)
+}
+
+pub fn part1(map: &HeatLossMap) -> u32 {
+    let mut ways = Ways::new(map);
+    let mut steps = vec![Step::first()];
+    let mut min_heat_loss = None;
+    while !steps.is_empty() {
+        for step in {
+            let iter = steps.clone();
+            steps = Vec::new();
+            iter
+        } {
+            trace!("steps", "{:?}", step);
+            for next_step in map.next_steps(&step) {
+                if ways.add(next_step.clone()) {
+                    if map.is_end(&next_step.cor) && min_heat_loss.is_none_or(|min| next_step.heat_loss < min) {
+                        min_heat_loss = Some(next_step.heat_loss);
+                        progress::candidate(next_step.heat_loss, "found new way with less heat loss");
+                    }
+                    trace!("steps", "  -> [YES] {:?}", next_step);
+                    steps.push(next_step.clone());
+                } else {
+                    trace!("steps", "  -> [NO ] {:?}", next_step);
+                }
+            }
+        }
+        trace!("steps", "==========");
+    }
+    debug!("ways", "number of steps kept per field:\n{}", ways);
+    stats::record("states_explored", ways.explored);
+    stats::record("states_discarded", ways.discarded);
+    ways.steps.tiles().last().unwrap().iter().map(|step| step.heat_loss).min().unwrap()
+}
+
+
+impl HeatLossMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self { map: Grid::parse_with(input, |c| c.to_digit(10).map(|n| n as HeatLoss))? })
+    }
+
+    fn rows(&self) -> usize {
+        self.map.rows()
+    }
+
+    fn cols(&self) -> usize {
+        self.map.cols()
+    }
+
+    fn is_end(&self, cor: &Cor) -> bool {
+        cor.row == self.rows() - 1 && cor.col == self.cols() - 1
+    }
+
+    fn next_steps(&self, last_step: &Step) -> Vec<Step> {
+        let mut out = Vec::new();
+        if self.is_end(&last_step.cor) {
+            return out;
+        }
+        for direction in [Direction::S, Direction::E, Direction::N, Direction::W] {
+            if let Some(cor) = self.map.step(last_step.cor, direction) {
+                out.push(self.next_step(last_step, direction, cor));
+            }
+        }
+        out.retain(|s| s.same_direction_counter < 3 && s.direction != last_step.direction.opposite());
+
+        out
+    }
+
+    fn next_step(&self, last_step: &Step, direction: Direction, new_cor: Cor) -> Step {
+        Step {
+            direction,
+            cor: new_cor,
+            heat_loss: last_step.heat_loss + self.get_heat_loss(&new_cor) as CumulatedHeatLoss,
+            same_direction_counter: if direction == last_step.direction {
+                last_step.same_direction_counter + 1
+            } else {
+                0
+            },
+        }
+    }
+
+    fn get_heat_loss(&self, cor: &Cor) -> HeatLoss {
+        self.map[*cor]
+    }
+}
+
+impl Step {
+    fn first() -> Self {
+        Self {
+            cor: Cor::default(),
+            direction: Direction::E,
+            heat_loss: 0,
+            same_direction_counter: 0,
+        }
+    }
+
+    fn may_turn_out_better(&self, other: &Step) -> bool {
+        self.heat_loss < other.heat_loss || self.direction != other.direction || self.same_direction_counter < other.same_direction_counter
+    }
+}
+
+impl Ways {
+    fn new(map: &HeatLossMap) -> Self {
+        Self { steps: Grid::new(map.rows(), map.cols(), Vec::new()), explored: 0, discarded: 0 }
+    }
+
+    fn add(&mut self, step: Step) -> bool {
+        let steps = &mut self.steps[step.cor];
+        if steps.iter().all(|s| step.may_turn_out_better(s)) {
+            steps.push(step);
+            self.explored += 1;
+            return true;
+        }
+        self.discarded += 1;
+        false
+    }
+}
+
+impl std::fmt::Display for Ways {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in self.steps.iter_rows() {
+            for cell in row {
+                write!(f, "{} ", cell.len())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+// running this code takes three and a half minutes
+
+
+use std::collections::HashSet;
+
+use common::debug;
+use common::parse::{self, ParseError};
+use grid::{Direction, RelativeDirection};
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static RE_DIG_INSTRUCTION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?<direction>[LRUD]) *(?<distance>[0-9]+) *\(#(?<red>[0-9A-Fa-f]{2})(?<green>[0-9A-Fa-f]{2})(?<blue>[0-9A-Fa-f]{2})\)$").unwrap());
+
+type CorType = i32;
+
+#[allow(dead_code)]
+struct Color {
+    red: u8,
+    green: u8,
+    blue: u8
+}
+
+pub struct DigInstruction {
+    direction: Direction,
+    distance: CorType,
+    #[allow(dead_code)]
+    color: Color,
+}
+
+type Cor = grid::Cor<CorType>;
+
+
+// the trench has to be a loop which doesn't cross itself, otherwise there is no inside to fill
+pub fn parse(input: &str) -> Result<Vec<DigInstruction>, ParseError> {
+    let instructions = input.lines().map(DigInstruction::parse).collect::<Result<Vec<_>, _>>()?;
+    let Some(last) = input.lines().last() else {
+        return Err(ParseError::missing(input, "input contains no instructions"));
+    };
+
+    let start = Cor::default();
+    let mut cor = start;
+    let mut visited = HashSet::from([start]);
+    for (i, (instruction, ln)) in instructions.iter().zip(input.lines()).enumerate() {
+        for step in 1..=instruction.distance {
+            cor += instruction.direction;
+            let closes = i + 1 == instructions.len() && step == instruction.distance && cor == start;
+            if !visited.insert(cor) && !closes {
+                return Err(ParseError::new(ln, "trench crosses itself"));
+            }
+        }
+    }
+    if cor != start {
+        return Err(ParseError::new(last, "trench does not return to its start"));
+    }
+    Ok(instructions)
+}
+
+pub fn part1(instructions: &[DigInstruction]) -> usize {
+    let mut cor = Cor::default();
+    let mut border = Vec::new();
+    for instruction in instructions {
+        for _ in 0..instruction.distance {
+            cor += instruction.direction;
+            border.push(cor);
+        }
+    }
+    debug!("border", "\n{}", render_cors(&border));
+
+    let filled = fill(&border);
+    debug!("filled", "\n{}", render_cors(&filled));
+
+    filled.len()
+}
+
+fn get_top_left(cors: &[Cor]) -> Cor {
+    Cor {
+        row: cors.iter().map(|cor| cor.row).min().unwrap(),
+        col: cors.iter().map(|cor| cor.col).min().unwrap(),
+    }
+}
+
+fn get_bottom_right(cors: &[Cor]) -> Cor {
+    Cor {
+        row: cors.iter().map(|cor| cor.row).max().unwrap() + 1,
+        col: cors.iter().map(|cor| cor.col).max().unwrap() + 1,
+    }
+}
+
+fn render_cors(cors: &[Cor]) -> String {
+    let mut out = String::new();
+    let tl = get_top_left(cors);
+    let br = get_bottom_right(cors);
+    for row in tl.row..br.row {
+        for col in tl.col..br.col {
+            if cors.contains(&Cor{row, col}) {
+                out.push('#');
+            } else {
+                out.push('.');
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn fill(cors: &[Cor]) -> Vec<Cor> {
+    let directions = cors.iter().zip(cors.iter().skip(1)).map(|(this, last)| to_direction(last, this)).collect::<Vec<_>>();
+    let mut relative_directions = directions.iter().zip(directions.iter().skip(1)).map(|(this, last)| to_relative_direction(last, this)).collect::<Vec<_>>();
+    relative_directions.insert(0, RelativeDirection::Straight);
+    let inside = if relative_directions.iter().filter(|d| **d==RelativeDirection::Left).count() > relative_directions.iter().filter(|d| **d==RelativeDirection::Right).count() {
+        RelativeDirection::Left
+    } else {
+        RelativeDirection::Right
+    };
+
+    let mut out = Vec::new();
+    for ((cor, direction), relative_direction) in cors.iter().zip(directions).zip(relative_directions) {
+        let cor = *cor;
+        out.push(cor);
+        for rd in if relative_direction == inside {
+            vec![]
+        } else if relative_direction == RelativeDirection::Straight {
+            vec![inside]
+        } else {
+            vec![inside, RelativeDirection::Straight]
+        } {
+            let ad = direction.turn(rd);
+            let mut c = cor + ad;
+            while !cors.contains(&c) {
+                if !out.contains(&c) {
+                    out.push(c);
+                }
+                c += ad;
+            }
+        }
+    }
+    out.push(cors[cors.len()-1]);
+    out
+}
+
+fn to_direction(last: &Cor, this: &Cor) -> Direction {
+    if this == last {
+        panic!("can't get direction between two identical coordinates");
+    }
+    last.direction_to(*this).expect("is not a straight direction")
+}
+
+fn to_relative_direction(last: &Direction, this: &Direction) -> RelativeDirection {
+    let relative_direction = last.relative(*this);
+    if relative_direction == RelativeDirection::Back {
+        panic!("180° change not possible");
+    }
+    relative_direction
+}
+
+impl DigInstruction {
+    fn parse(ln: &str) -> Result<Self, ParseError> {
+        let caps = RE_DIG_INSTRUCTION.captures(ln).ok_or_else(|| ParseError::new(ln, "expected an instruction like 'R 6 (#70c710)' but got"))?;
+        let distance = caps.name("distance").unwrap().as_str();
+        Ok(Self {
+            direction: parse_direction(caps.name("direction").unwrap().as_str())?,
+            distance: match parse::number(distance)? {
+                0 => return Err(ParseError::new(distance, "distance has to be positive")),
+                distance => distance,
+            },
+            color: Color {
+                red: u8::from_str_radix(caps.name("red").unwrap().as_str(), 16).expect("failed to parse hex number for red"),
+                green: u8::from_str_radix(caps.name("green").unwrap().as_str(), 16).expect("failed to parse hex number for green"),
+                blue: u8::from_str_radix(caps.name("blue").unwrap().as_str(), 16).expect("failed to parse hex number for blue"),
+            },
+        })
+    }
+}
+
+fn parse_direction(direction: &str) -> Result<Direction, ParseError> {
+    match direction {
+        "L" => Ok(Direction::W),
+        "R" => Ok(Direction::E),
+        "U" => Ok(Direction::N),
+        "D" => Ok(Direction::S),
+        _ => Err(ParseError::new(direction, "invalid direction")),
+    }
+}
@@ -1,12 +1,11 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_str,parse_macro_input,LitStr};
-use home_dir::HomeDirExt;
 
 #[proc_macro]
-pub fn load(filename: TokenStream) -> TokenStream {
-    let filename = parse_macro_input!(filename as LitStr).value();
-    let filename = std::path::Path::new(&filename).expand_home().unwrap();
+pub fn load(name: TokenStream) -> TokenStream {
+    let name = parse_macro_input!(name as LitStr).value();
+    let filename = common::input::resolve(19, &name);
 
     let mut out = quote! {
         type CategoryType = u32;
@@ -27,9 +26,28 @@ pub fn load(filename: TokenStream) -> TokenStream {
         }
     };
 
+    let input = match common::crypt::read(&filename) {
+        Ok(input) => input,
+        Err(err) => {
+            // without the input the crate still has to build, part1 reports the error instead
+            let err = err.to_string();
+            out.extend(quote! {
+                const INPUT_ERROR: Option<&str> = Some(#err);
+
+                fn r#in(part: &Part) -> bool {
+                    false
+                }
+
+                const PARTS: [Part; 0] = [];
+            });
+            return out.into();
+        }
+    };
+    out.extend(quote!(const INPUT_ERROR: Option<&str> = None;));
+
     let mut reading_rules = true;
     let mut parts: Vec<proc_macro2::TokenStream> = Vec::new();
-    for ln in std::fs::read_to_string(&filename).unwrap_or_else(|_| panic!("missing input file '{:?}'", filename)).lines() {
+    for ln in input.lines() {
         if ln.is_empty() {
             reading_rules = false;
             out.extend(quote!(  ));
@@ -73,6 +91,5 @@ fn workflow_to_rust_code(workflow: &str) -> proc_macro2::TokenStream {
         }
     }
     out.push('}');
-    //println!("{}", out);
     parse_str(&out).expect("failed to parse workflow")
 }
@@ -0,0 +1,17 @@
+use macros::load;
+
+load!("input");
+
+// there is no parse since the workflows and parts are compiled in by load!()
+pub fn part1() -> Result<u32, String> {
+    if let Some(err) = INPUT_ERROR {
+        return Err(err.to_string());
+    }
+    let mut result: u32 = 0;
+    for part in PARTS {
+        if r#in(&part) {
+            result += part.x + part.m + part.a + part.s;
+        }
+    }
+    Ok(result)
+}
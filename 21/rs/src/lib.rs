@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use common::parse::{self, ParseError};
+use common::trace;
+use grid::{Direction, Grid};
+
+type CorType = i16;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Garden,
+    Rock,
+    Start,
+}
+
+pub struct Map {
+    grid: Grid<Tile>,
+    start: Cor,
+}
+
+type Cor = grid::Cor<CorType>;
+
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::parse(input)
+}
+
+pub fn part1(map: &Map) -> usize {
+    reachable(map, 64)
+}
+
+// the map repeats infinitely in every direction
+pub fn reachable(map: &Map, steps: u32) -> usize {
+    let mut pos = HashSet::new();
+    pos.insert(map.start);
+
+    for step in 0..steps {
+        pos = pos.iter().flat_map(|c| map.get_next_cors(*c)).collect();
+        trace!("positions", "after step {}:\n{}", step + 1, map.render_positions(&pos));
+    }
+
+    pos.len()
+}
+
+
+impl Map {
+    // there has to be exactly one start tile
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input)?;
+        let starts = grid.iter().filter(|(_, tile)| **tile == Tile::Start).map(|(cor, _)| cor).collect::<Vec<_>>();
+        let start = match starts[..] {
+            [start] => Cor { row: start.row as CorType, col: start.col as CorType },
+            [] => return Err(ParseError::missing(input, "input did not contain a start tile")),
+            [_, other, ..] => {
+                let token = input.lines().nth(other.row).and_then(|ln| parse::chars(ln).nth(other.col)).map_or(input, |(_, token)| token);
+                return Err(ParseError::new(token, "second start tile"));
+            }
+        };
+        Ok(Self { grid, start })
+    }
+
+    fn get_next_cors(&self, cor: Cor) -> Vec<Cor> {
+        let mut out = Direction::ALL.map(|d| cor + d).to_vec();
+        out.retain(|c| self.get(c) != Tile::Rock);
+        out
+    }
+
+    fn get(&self, cor: &Cor) -> Tile {
+        *self.grid.get_wrapping(cor.row.into(), cor.col.into())
+    }
+
+    fn render_positions(&self, pos: &HashSet<Cor>) -> String {
+        let mut out = String::new();
+        for (row, cols) in self.grid.iter_rows().enumerate() {
+            for (col, cell) in cols.iter().enumerate() {
+                let symbol = if pos.contains(&Cor{row: row as CorType, col: col as CorType}) {
+                    'O'
+                } else if cell == &Tile::Rock {
+                    '#'
+                } else {
+                    '.'
+                };
+                out.push(symbol);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl grid::Tile for Tile {
+    fn from_char(symbol: char) -> Option<Self> {
+        match symbol {
+            '.' => Some(Self::Garden),
+            '#' => Some(Self::Rock),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Garden => '.',
+            Self::Rock => '#',
+            Self::Start => 'S',
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Map,Tile,Cor,CorType,reachable};
+
+    #[test]
+    fn test_get_simple_garden() {
+        let map = Map::parse(&common::input::read_example(21, "exp")).unwrap();
+        assert_eq!(map.get(&Cor{row: 0, col: 0}), Tile::Garden);
+        assert_eq!(map.get(&Cor{row: 2, col: 1}), Tile::Rock);
+        assert_eq!(map.get(&Cor{row: 5, col: 5}), Tile::Start);
+    }
+
+    #[test]
+    fn test_get_infinite_garden() {
+        let map = Map::parse(&common::input::read_example(21, "exp")).unwrap();
+        assert_eq!(map.get(&Cor{row: -1, col: 0}), Tile::Garden);
+        assert_eq!(map.get(&Cor{row: 0, col: -1}), Tile::Garden);
+        assert_eq!(map.get(&Cor{row: map.grid.rows() as CorType, col: 0}), Tile::Garden);
+        assert_eq!(map.get(&Cor{row: 0, col: map.grid.cols() as CorType}), Tile::Garden);
+    }
+
+    #[test]
+    fn test_reachable_from_puzzle_text() {
+        let map = Map::parse(&common::input::read_example(21, "exp")).unwrap();
+        assert_eq!(reachable(&map, 6), 16);
+        assert_eq!(reachable(&map, 10), 50);
+        assert_eq!(reachable(&map, 50), 1594);
+        assert_eq!(reachable(&map, 100), 6536);
+    }
+
+    #[test]
+    fn test_start_tiles() {
+        assert_eq!(Map::parse("...\n.S.\n...").unwrap().start, Cor{row: 1, col: 1});
+        assert!(Map::parse("...\n...").is_err());
+        let input = ".S.\n..S";
+        let err = Map::parse(input).err().unwrap().locate(input);
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+    }
+}
@@ -0,0 +1,243 @@
+use common::parse::{self, ParseError};
+use common::{debug, trace};
+
+type CorType = u16;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct Cor {
+    x: CorType,
+    y: CorType,
+    z: CorType,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct Brick {
+    name: char,
+    c1: Cor,
+    c2: Cor,
+}
+
+#[derive(Clone)]
+pub struct Pile {
+    bricks: Vec<Brick>,
+}
+
+
+pub fn parse(input: &str) -> Result<Pile, ParseError> {
+    Pile::parse(input.lines())
+}
+
+pub fn part1(pile: &Pile) -> u32 {
+    let mut pile = pile.clone();
+    debug!("pile", "\n{}\n{}", pile.render_x(), pile.render_y());
+
+    pile.let_gravity_do_its_thing();
+    debug!("pile", "after gravity has done it's thing:\n{}\n{}", pile.render_x(), pile.render_y());
+
+    let mut result = 0;
+    for brick in &pile.bricks {
+        if pile.all_supported_bricks_have_other_supporters(brick) {
+            result += 1;
+        }
+    }
+    result
+}
+
+
+impl Pile {
+    fn parse<'a>(lines: impl Iterator<Item=&'a str>) -> Result<Self, ParseError> {
+        let mut name = 'A';
+        Ok(Self {
+            bricks: lines.map(|ln| {
+                let (c1, c2) = parse::split_once(ln, "~")?;
+                let c1 = Cor::parse(c1)?;
+                let c2 = Cor::parse(c2)?;
+                let out = Brick { c1, c2, name };
+                name = std::char::from_u32( name as u32 + 1 ).unwrap();
+                Ok(out)
+            }).collect::<Result<_, _>>()?
+        })
+    }
+
+    fn let_gravity_do_its_thing(&mut self) {
+        self.bricks.sort_by_key(|b| b.min_z());
+        for (i, brick) in self.bricks.clone().iter().enumerate() {
+            let z = self.bricks.iter().filter(|b| b.is_under(brick)).map(|b| b.max_z() + 1).max().unwrap_or(0);
+            trace!("gravity", "    lower {} by {} - {}", brick.name, brick.min_z(), z);
+            self.bricks[i] = brick.lower(brick.min_z() - z);
+        }
+    }
+
+    fn all_supported_bricks_have_other_supporters(&self, brick: &Brick) -> bool {
+        for supported in self.bricks.iter().filter(|b| brick.supports(b)) {
+            if !self.has_other_supporters(supported, brick) {
+                return false;
+            }
+        }
+        true
+    }
+    fn has_other_supporters(&self, brick: &Brick, supporter: &Brick) -> bool {
+        for b in &self.bricks {
+            if b != supporter && b.supports(brick) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn render_x(&self) -> String {
+        let mut out = String::new();
+        let max_z = self.bricks.iter().map(|b| b.max_z()).max().unwrap();
+        let max_x = self.bricks.iter().map(|b| b.max_x()).max().unwrap();
+
+        out += " ^\n";
+
+        for z in (0..=max_z).rev() {
+            out += &z.to_string();
+            if z == max_z / 2 {
+                out += "z ";
+            } else {
+                out += "| ";
+            }
+
+            for x in 0..=max_x {
+                let brick = self
+                    .bricks
+                    .iter()
+                    .filter(
+                        |b| b.min_x() <= x && x <= b.max_x()
+                        &&  b.min_z() <= z && z <= b.max_z()
+                    ).min_by_key(|b| b.min_y())
+                    .map(|b| b.name)
+                    .unwrap_or(' ');
+                out.push(brick);
+            }
+            out.push('\n');
+        }
+
+        out += &format!("   -{:-^w$}>\n", "x", w=max_x as usize);
+        out += "   ";
+        for x in 0..=max_x {
+            out.push(x.to_string().chars().next().unwrap());
+        }
+        out.push('\n');
+        out
+    }
+    fn render_y(&self) -> String {
+        let mut out = String::new();
+        let max_z = self.bricks.iter().map(|b| b.max_z()).max().unwrap();
+        let max_y = self.bricks.iter().map(|b| b.max_y()).max().unwrap();
+
+        out += " ^\n";
+
+        for z in (0..=max_z).rev() {
+            out += &z.to_string();
+            if z == max_z / 2 {
+                out += "z ";
+            } else {
+                out += "| ";
+            }
+
+            for y in 0..=max_y {
+                let brick = self
+                    .bricks
+                    .iter()
+                    .filter(
+                        |b| b.min_y() <= y && y <= b.max_y()
+                        &&  b.min_z() <= z && z <= b.max_z()
+                    ).min_by_key(|b| b.min_y())
+                    .map(|b| b.name)
+                    .unwrap_or(' ');
+                out.push(brick);
+            }
+            out.push('\n');
+        }
+
+        out += &format!("   -{:-^w$}>\n", "y", w=max_y as usize);
+        out += "   ";
+        for x in 0..=max_y {
+            out.push(x.to_string().chars().next().unwrap());
+        }
+        out.push('\n');
+        out
+    }
+}
+
+impl Cor {
+    fn parse(val: &str) -> Result<Self, ParseError> {
+        let mut c = val.split(',');
+        let mut next = |name| c.next().ok_or_else(|| ParseError::missing(val, format!("missing {name} coordinate"))).and_then(parse::number);
+        let x: CorType = next("x")?;
+        let y: CorType = next("y")?;
+        let z: CorType = next("z")?;
+        if let Some(dimension) = c.next() {
+            return Err(ParseError::new(dimension, "more than three dimensions given for coordinate"));
+        }
+        Ok(Self { x, y, z })
+    }
+}
+
+impl Brick {
+    fn min_z(&self) -> CorType {
+        if self.c1.z < self.c2.z {
+            self.c1.z
+        } else {
+            self.c2.z
+        }
+    }
+    fn max_z(&self) -> CorType {
+        if self.c1.z > self.c2.z {
+            self.c1.z
+        } else {
+            self.c2.z
+        }
+    }
+
+    fn min_y(&self) -> CorType {
+        if self.c1.y < self.c2.y {
+            self.c1.y
+        } else {
+            self.c2.y
+        }
+    }
+    fn max_y(&self) -> CorType {
+        if self.c1.y > self.c2.y {
+            self.c1.y
+        } else {
+            self.c2.y
+        }
+    }
+
+    fn min_x(&self) -> CorType {
+        if self.c1.x < self.c2.x {
+            self.c1.x
+        } else {
+            self.c2.x
+        }
+    }
+    fn max_x(&self) -> CorType {
+        if self.c1.x > self.c2.x {
+            self.c1.x
+        } else {
+            self.c2.x
+        }
+    }
+
+    fn is_under(&self, other: &Self) -> bool {
+           self.min_x() <= other.max_x() && self.max_x() >= other.min_x()
+        && self.min_y() <= other.max_y() && self.max_y() >= other.min_y()
+        && self.max_z() < other.min_z()
+    }
+
+    fn supports(&self, other: &Self) -> bool {
+        self.is_under(other) && self.max_z() + 1 == other.min_z()
+    }
+
+    fn lower(&self, z: CorType) -> Self {
+        Self {
+            c1: Cor { z: self.c1.z - z, ..self.c1 },
+            c2: Cor { z: self.c2.z - z, ..self.c2 },
+            name: self.name,
+        }
+    }
+}
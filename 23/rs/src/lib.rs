@@ -0,0 +1,215 @@
+use std::fmt;
+
+use common::parse::ParseError;
+use common::{debug, progress, stats};
+use grid::{Cor, Direction, Grid, Tile as _};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Tile {
+    Forest,
+    Path,
+    Slope(Direction),
+}
+
+pub struct Map {
+    map: Grid<Tile>,
+    // below the entrance in the first row
+    start: Step,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct Step {
+    cor: Cor,
+    direction: Direction,
+}
+
+#[derive(Clone, Debug)]
+struct Hike {
+    steps: Vec<Step>,
+}
+
+// the number of steps of the longest hike, None if no hike reaches the exit
+pub struct HikeLength(pub Option<usize>);
+
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::parse(input)
+}
+
+// takes 10 seconds to run
+pub fn part1(map: &Map) -> HikeLength {
+    longest_hike(map, true)
+}
+
+// the correct solution was printed within quarter of an hour but I don't know how long this program would have taken to try all possible ways
+pub fn part2(map: &Map) -> HikeLength {
+    longest_hike(map, false)
+}
+
+// slopes can only be walked down if they are slippery, otherwise they are like paths
+fn longest_hike(map: &Map, slippery: bool) -> HikeLength {
+    let mut unfinished_hikes = vec![Hike::new(map.start)];
+    let mut longest_hike: Option<Hike> = None;
+    let (mut hikes, mut dead_ends) = (0, 0);
+    while let Some(mut hike) = unfinished_hikes.pop() {
+        loop {
+            let mut next_steps = map.get_next(hike.steps.last().unwrap(), slippery);
+            next_steps.retain(|next_step| hike.steps.iter().all(|old_step| old_step.cor != next_step.cor));
+            for s in next_steps.iter().skip(1) {
+                unfinished_hikes.push({
+                    let mut cloned_hike = hike.clone();
+                    cloned_hike.step(*s);
+                    cloned_hike
+                })
+            }
+            if let Some(s) = next_steps.first() {
+                hike.step(*s);
+                if map.is_end(&s.cor) {
+                    hikes += 1;
+                    if longest_hike.is_none() || hike.steps.len() > longest_hike.as_ref().unwrap().steps.len() {
+                        progress::candidate(hike.steps.len(), &format!("found new candidate with {} steps", hike.steps.len()));
+                        longest_hike = Some(hike);
+                    }
+                    break;
+                }
+            } else {
+                // dead end, drop current route, continue with next possibility
+                dead_ends += 1;
+                break;
+            }
+        }
+    }
+
+    stats::record("hikes", hikes);
+    stats::record("dead_ends", dead_ends);
+
+    let Some(longest_hike) = longest_hike else {
+        debug!("hike", "no hike reaches the exit");
+        return HikeLength(None);
+    };
+    debug!("hike", "longest hike:\n{}", map.render_hike(&longest_hike));
+    HikeLength(Some(longest_hike.steps.len()))
+}
+
+
+impl Map {
+    // the entrance is the path in the first row, the hike starts on the tile below it
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input)?;
+        let first = input.lines().next().ok_or_else(|| ParseError::missing(input, "input is empty"))?;
+        let col = map.row(0).iter().position(|tile| tile == &Tile::Path).ok_or_else(|| ParseError::new(first, "there is no entrance to the hiking trail"))?;
+        let start = Step { cor: Cor { row: 1, col }, direction: Direction::S };
+        if map.get(start.cor).is_none_or(|tile| tile == &Tile::Forest) {
+            return Err(ParseError::new(&first[col..col + 1], "the entrance leads nowhere"));
+        }
+        Ok(Self { map, start })
+    }
+
+    fn get_next(&self, step: &Step, slippery: bool) -> Vec<Step> {
+        let mut out = Vec::new();
+        for d in [Direction::S, Direction::E, Direction::W, Direction::N] {
+            if d == step.direction.opposite() {
+                continue;
+            }
+            let Some(next_cor) = self.map.step(step.cor, d) else {
+                continue;
+            };
+            let next_tile = self.get(&next_cor);
+            let walkable = match next_tile {
+                Tile::Forest => false,
+                Tile::Path => true,
+                Tile::Slope(slope) => !slippery || slope == d,
+            };
+            if walkable {
+                out.push(Step{ cor: next_cor, direction: d });
+            }
+        }
+        out
+    }
+
+    fn is_end(&self, cor: &Cor) -> bool {
+        cor.row == self.rows() - 1
+    }
+
+    fn get(&self, cor: &Cor) -> Tile {
+        self.map[*cor]
+    }
+    fn rows(&self) -> usize {
+        self.map.rows()
+    }
+    fn cols(&self) -> usize {
+        self.map.cols()
+    }
+
+    fn render_hike(&self, hike: &Hike) -> String {
+        let mut out = String::new();
+        for row in 0..self.rows() {
+            for col in 0..self.cols() {
+                out.push({
+                    if let Some(step) = hike.steps.iter().find(|s| s.cor == Cor{row, col}) {
+                        let tile = self.get(&Cor{row, col});
+                        if tile == Tile::Forest {
+                            'X'
+                        } else if let Tile::Slope(_) = tile {
+                            tile.to_char()
+                        } else {
+                        match step.direction {
+                            Direction::N => '↑',
+                            Direction::S => '↓',
+                            Direction::W => '←',
+                            Direction::E => '→',
+                        }}
+                    } else {
+                        self.get(&Cor{row, col}).to_char()
+                    }
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl grid::Tile for Tile {
+    fn from_char(symbol: char) -> Option<Self> {
+        match symbol {
+            '#' => Some(Self::Forest),
+            '.' => Some(Self::Path),
+            '^' => Some(Self::Slope(Direction::N)),
+            'v' => Some(Self::Slope(Direction::S)),
+            '<' => Some(Self::Slope(Direction::W)),
+            '>' => Some(Self::Slope(Direction::E)),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Forest => '#',
+            Self::Path => '.',
+            Self::Slope(Direction::N) => '^',
+            Self::Slope(Direction::S) => 'v',
+            Self::Slope(Direction::W) => '<',
+            Self::Slope(Direction::E) => '>',
+        }
+    }
+}
+
+impl fmt::Display for HikeLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(steps) => write!(f, "{steps}"),
+            None => f.write_str("no hike reaches the exit"),
+        }
+    }
+}
+
+impl Hike {
+    fn new(step: Step) -> Self {
+        Self { steps: vec![step] }
+    }
+
+    fn step(&mut self, step: Step) {
+        self.steps.push(step);
+    }
+}
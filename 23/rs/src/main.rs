@@ -0,0 +1,11 @@
+fn main() {
+    let (part, map) = common::input::parse_part_from_args(23, rs_23::parse);
+    let longest = match part {
+        1 => rs_23::part1(&map),
+        _ => rs_23::part2(&map),
+    };
+    match longest.0 {
+        Some(steps) => println!("longest hike has {steps} steps"),
+        None => println!("no hike reaches the exit"),
+    }
+}
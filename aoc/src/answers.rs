@@ -0,0 +1,79 @@
+use std::path::Path;
+
+// the answer files have grown different names over the days:
+// correct-answer.txt, correct-answers.txt, correct-solution, correct-result, ...
+const FILE_PREFIX: &str = "correct-";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub value: String,
+}
+
+pub fn read(day_dir: &Path) -> Result<Vec<Answer>, String> {
+    let Ok(entries) = std::fs::read_dir(day_dir) else {
+        return Ok(Vec::new());
+    };
+    let mut out = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        if !entry.file_name().to_string_lossy().starts_with(FILE_PREFIX) {
+            continue;
+        }
+        let path = entry.path();
+        let text = std::fs::read_to_string(&path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+        out.extend(parse(&text).map_err(|err| format!("{}: {err}", path.display()))?);
+    }
+    out.sort_by_key(|a| (a.day, a.part));
+    Ok(out)
+}
+
+pub fn parse(text: &str) -> Result<Vec<Answer>, String> {
+    text.lines().filter(|ln| !ln.trim().is_empty()).map(Answer::parse).collect()
+}
+
+impl Answer {
+    // accepts "1-1: 55607" as well as "13-1 30802"
+    fn parse(ln: &str) -> Result<Self, String> {
+        let (key, value) = ln.trim().split_once(char::is_whitespace).ok_or_else(|| format!("missing answer in line '{ln}'"))?;
+        let key = key.strip_suffix(':').unwrap_or(key);
+        let (day, part) = key.split_once('-').ok_or_else(|| format!("invalid day-part '{key}'"))?;
+        Ok(Self {
+            day: day.parse().map_err(|_| format!("invalid day '{day}'"))?,
+            part: part.parse().map_err(|_| format!("invalid part '{part}'"))?,
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{parse, Answer};
+
+    fn answer(day: u8, part: u8, value: &str) -> Answer {
+        Answer { day, part, value: value.to_string() }
+    }
+
+    #[test]
+    fn test_parse_with_colon() {
+        assert_eq!(parse("1-1: 55607\n1-2: 55291\n"), Ok(vec![answer(1, 1, "55607"), answer(1, 2, "55291")]));
+    }
+
+    #[test]
+    fn test_parse_without_colon() {
+        assert_eq!(parse("13-1 30802\n"), Ok(vec![answer(13, 1, "30802")]));
+    }
+
+    #[test]
+    fn test_parse_ignores_empty_lines() {
+        assert_eq!(parse("\n11-2: 630728425490\n\n"), Ok(vec![answer(11, 2, "630728425490")]));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("1-1:").is_err());
+        assert!(parse("11: 42").is_err());
+        assert!(parse("a-1: 42").is_err());
+    }
+}
@@ -0,0 +1,392 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use common::input::{parse_part, Source};
+use common::log::Verbosity;
+
+use crate::guesses::Verdict;
+
+pub const USAGE: &str = "\
+usage: aoc run <day> <part> [--input PATH | --example NAME] [--timeout SECONDS | --json] [-v | -vv] [--trace CATEGORY,...]
+       aoc verify [--timeout SECONDS] [<day>...]
+       aoc batch <day> <dir> [--timeout SECONDS]
+       aoc bench [--iterations N] [--timeout SECONDS] [<day>...]
+       aoc guess <day> <part> <answer> too-high|too-low|wrong|correct
+       aoc download [<day>...]
+       aoc submit <day> <part> [<answer>] [--force]
+       aoc encrypt [<day>...]
+       aoc decrypt [<day>...]
+       aoc extract [--force] [<day>...]
+       aoc new <day>
+       aoc dashboard";
+
+pub enum Command {
+    Run {
+        day: u8,
+        part: u8,
+        input: Source,
+        // None if the solver may take as long as it takes
+        timeout: Option<Duration>,
+        json: bool,
+        verbosity: Verbosity,
+    },
+    Verify {
+        days: Vec<u8>,
+        timeout: Option<Duration>,
+    },
+    Batch {
+        day: u8,
+        dir: PathBuf,
+        timeout: Option<Duration>,
+    },
+    Extract {
+        days: Vec<u8>,
+        force: bool,
+    },
+    Bench {
+        days: Vec<u8>,
+        iterations: u32,
+        // parts which take longer once are skipped
+        timeout: Option<Duration>,
+    },
+    Guess {
+        day: u8,
+        part: u8,
+        value: String,
+        verdict: Verdict,
+    },
+    Download {
+        days: Vec<u8>,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        // computed by the solver if not given
+        answer: Option<String>,
+        force: bool,
+    },
+    Encrypt {
+        days: Vec<u8>,
+    },
+    Decrypt {
+        days: Vec<u8>,
+    },
+    New {
+        day: u8,
+    },
+    Dashboard,
+}
+
+const DEFAULT_ITERATIONS: u32 = 5;
+// verify and bench run everything, some solvers would block them for hours without a time budget
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        match args.next().as_deref() {
+            Some("run") => Self::parse_run(args),
+            Some("verify") => Self::parse_verify(args),
+            Some("batch") => Self::parse_batch(args),
+            Some("extract") => Self::parse_extract(args),
+            Some("bench") => Self::parse_bench(args),
+            Some("guess") => Self::parse_guess(args),
+            Some("download") => Ok(Self::Download { days: parse_days(args)? }),
+            Some("submit") => Self::parse_submit(args),
+            Some("encrypt") => Ok(Self::Encrypt { days: parse_days(args)? }),
+            Some("decrypt") => Ok(Self::Decrypt { days: parse_days(args)? }),
+            Some("new") => Self::parse_new(args),
+            Some("dashboard") => match args.next() {
+                Some(arg) => Err(format!("unexpected argument '{arg}'")),
+                None => Ok(Self::Dashboard),
+            },
+            Some(cmd) => Err(format!("unknown command '{cmd}'")),
+            None => Err("missing command".to_string()),
+        }
+    }
+
+    fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or("missing day")?)?;
+        let part = parse_part(&args.next().ok_or("missing part")?)?;
+        let mut input = Source::Input;
+        let mut timeout = None;
+        let mut json = false;
+        let mut verbosity = Verbosity::default();
+        while let Some(arg) = args.next() {
+            if arg == "--timeout" {
+                timeout = parse_timeout(args.next())?;
+            } else if arg == "--json" {
+                json = true;
+            } else if !input.parse_arg(&arg, &mut args)? && !verbosity.parse_arg(&arg, &mut args)? {
+                return Err(format!("unexpected argument '{arg}'"));
+            }
+        }
+        // the timings and stats would be lost in the child process the timeout runs the solver in
+        if json && timeout.is_some() {
+            return Err("--json can't be combined with --timeout".to_string());
+        }
+        Ok(Self::Run { day, part, input, timeout, json, verbosity })
+    }
+
+    fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut timeout = Some(DEFAULT_TIMEOUT);
+        let mut days = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg == "--timeout" {
+                timeout = parse_timeout(args.next())?;
+            } else {
+                days.push(arg);
+            }
+        }
+        Ok(Self::Verify { days: parse_days(days.into_iter())?, timeout })
+    }
+
+    fn parse_batch(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or("missing day")?)?;
+        let dir = PathBuf::from(args.next().ok_or("missing directory")?);
+        let mut timeout = None;
+        while let Some(arg) = args.next() {
+            if arg == "--timeout" {
+                timeout = parse_timeout(args.next())?;
+            } else {
+                return Err(format!("unexpected argument '{arg}'"));
+            }
+        }
+        Ok(Self::Batch { day, dir, timeout })
+    }
+
+    fn parse_extract(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let (force, days): (Vec<_>, Vec<_>) = args.partition(|arg| arg == "--force");
+        Ok(Self::Extract { days: parse_days(days.into_iter())?, force: !force.is_empty() })
+    }
+
+    fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut iterations = DEFAULT_ITERATIONS;
+        let mut timeout = Some(DEFAULT_TIMEOUT);
+        let mut days = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg == "--timeout" {
+                timeout = parse_timeout(args.next())?;
+            } else if arg == "--iterations" {
+                let value = args.next().ok_or("missing value for --iterations")?;
+                iterations = match value.parse() {
+                    Ok(n @ 1..) => n,
+                    _ => return Err(format!("invalid number of iterations '{value}'")),
+                };
+            } else {
+                days.push(arg);
+            }
+        }
+        Ok(Self::Bench { days: parse_days(days.into_iter())?, iterations, timeout })
+    }
+
+    fn parse_guess(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or("missing day")?)?;
+        let part = parse_part(&args.next().ok_or("missing part")?)?;
+        let value = args.next().ok_or("missing answer")?;
+        let verdict = args.next().ok_or("missing verdict")?.parse()?;
+        if let Some(arg) = args.next() {
+            return Err(format!("unexpected argument '{arg}'"));
+        }
+        Ok(Self::Guess { day, part, value, verdict })
+    }
+
+    fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or("missing day")?)?;
+        if let Some(arg) = args.next() {
+            return Err(format!("unexpected argument '{arg}'"));
+        }
+        Ok(Self::New { day })
+    }
+
+    fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or("missing day")?)?;
+        let part = parse_part(&args.next().ok_or("missing part")?)?;
+        let (force, rest): (Vec<_>, Vec<_>) = args.partition(|arg| arg == "--force");
+        let [answer] = match &rest[..] {
+            [] => [None],
+            [answer] => [Some(answer.clone())],
+            [_, arg, ..] => return Err(format!("unexpected argument '{arg}'")),
+        };
+        Ok(Self::Submit { day, part, answer, force: !force.is_empty() })
+    }
+}
+
+// all days if none are given
+fn parse_days(args: impl Iterator<Item = String>) -> Result<Vec<u8>, String> {
+    let mut days = args.map(|arg| parse_day(&arg)).collect::<Result<Vec<_>, _>>()?;
+    if days.is_empty() {
+        days = (1..=25).collect();
+    }
+    Ok(days)
+}
+
+// in seconds, 0 disables the timeout
+fn parse_timeout(value: Option<String>) -> Result<Option<Duration>, String> {
+    let value = value.ok_or("missing value for --timeout")?;
+    match value.parse::<f64>() {
+        Ok(0.0) => Ok(None),
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Some(Duration::from_secs_f64(seconds))),
+        _ => Err(format!("invalid timeout '{value}', expected a number of seconds")),
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day '{day}', expected a number from 1 to 25")),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use common::input::Source;
+    use crate::args::Command;
+    use crate::guesses::Verdict;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_run_default_input() {
+        let Ok(Command::Run { day, part, input, .. }) = parse(&["run", "03", "2"]) else { panic!("failed to parse") };
+        assert_eq!((day, part, input), (3, 2, Source::Input));
+    }
+
+    #[test]
+    fn test_run_input() {
+        let Ok(Command::Run { day, part, input, .. }) = parse(&["run", "16", "1", "--input", "16/exp"]) else { panic!("failed to parse") };
+        assert_eq!((day, part, input), (16, 1, Source::Path(PathBuf::from("16/exp"))));
+    }
+
+    #[test]
+    fn test_run_example() {
+        let Ok(Command::Run { day, part, input, .. }) = parse(&["run", "10", "2", "--example", "exp2_gap"]) else { panic!("failed to parse") };
+        assert_eq!((day, part, input), (10, 2, Source::Example("exp2_gap".to_string())));
+    }
+
+    #[test]
+    fn test_verify_all_days() {
+        let Ok(Command::Verify { days, .. }) = parse(&["verify"]) else { panic!("failed to parse") };
+        assert_eq!(days, (1..=25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_verify_some_days() {
+        let Ok(Command::Verify { days, .. }) = parse(&["verify", "3", "12"]) else { panic!("failed to parse") };
+        assert_eq!(days, vec![3, 12]);
+    }
+
+    #[test]
+    fn test_batch() {
+        let Ok(Command::Batch { day, dir, timeout }) = parse(&["batch", "7", "inputs/07", "--timeout", "10"]) else { panic!("failed to parse") };
+        assert_eq!((day, dir, timeout), (7, PathBuf::from("inputs/07"), Some(Duration::from_secs(10))));
+        assert!(parse(&["batch", "7"]).is_err());
+        assert!(parse(&["batch", "7", "inputs/07", "8"]).is_err());
+    }
+
+    #[test]
+    fn test_run_stdin() {
+        let Ok(Command::Run { input, .. }) = parse(&["run", "7", "1", "--input", "-"]) else { panic!("failed to parse") };
+        assert_eq!(input, Source::Stdin);
+    }
+
+    #[test]
+    fn test_extract() {
+        let Ok(Command::Extract { days, force }) = parse(&["extract", "20", "--force"]) else { panic!("failed to parse") };
+        assert_eq!((days, force), (vec![20], true));
+        let Ok(Command::Extract { days, force }) = parse(&["extract"]) else { panic!("failed to parse") };
+        assert_eq!((days.len(), force), (25, false));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let Ok(Command::Run { timeout, .. }) = parse(&["run", "5", "2", "--timeout", "1.5", "--example", "exp"]) else { panic!("failed to parse") };
+        assert_eq!(timeout, Some(Duration::from_millis(1500)));
+        let Ok(Command::Run { timeout, .. }) = parse(&["run", "5", "2"]) else { panic!("failed to parse") };
+        assert_eq!(timeout, None);
+        let Ok(Command::Verify { days, timeout }) = parse(&["verify", "5", "--timeout", "0"]) else { panic!("failed to parse") };
+        assert_eq!((days, timeout), (vec![5], None));
+        let Ok(Command::Verify { timeout, .. }) = parse(&["verify"]) else { panic!("failed to parse") };
+        assert_eq!(timeout, Some(Duration::from_secs(60)));
+        assert!(parse(&["run", "5", "2", "--timeout"]).is_err());
+        assert!(parse(&["verify", "--timeout", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let Ok(Command::Run { json, .. }) = parse(&["run", "17", "1", "--json"]) else { panic!("failed to parse") };
+        assert!(json);
+        let Ok(Command::Run { json, .. }) = parse(&["run", "17", "1"]) else { panic!("failed to parse") };
+        assert!(!json);
+        assert!(parse(&["run", "17", "1", "--json", "--timeout", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_run_verbosity() {
+        let Ok(Command::Run { day, verbosity, .. }) = parse(&["run", "17", "1", "-vv", "--trace", "steps"]) else { panic!("failed to parse") };
+        assert_eq!((day, verbosity.to_args()), (17, vec!["-vv".to_string(), "--trace".to_string(), "steps".to_string()]));
+    }
+
+    #[test]
+    fn test_bench() {
+        let Ok(Command::Bench { days, iterations, timeout }) = parse(&["bench", "7", "--iterations", "20", "9", "--timeout", "0"]) else { panic!("failed to parse") };
+        assert_eq!((days, iterations, timeout), (vec![7, 9], 20, None));
+        let Ok(Command::Bench { days, iterations, timeout }) = parse(&["bench"]) else { panic!("failed to parse") };
+        assert_eq!((days.len(), iterations, timeout), (25, 5, Some(Duration::from_secs(60))));
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["bench", "--iterations"]).is_err());
+    }
+
+    #[test]
+    fn test_run_invalid() {
+        assert!(parse(&["run", "26", "1"]).is_err());
+        assert!(parse(&["run", "1", "3"]).is_err());
+        assert!(parse(&["run", "1"]).is_err());
+        assert!(parse(&["run", "1", "1", "--input"]).is_err());
+        assert!(parse(&["walk", "1", "1"]).is_err());
+        assert!(parse(&["verify", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_guess() {
+        let Ok(Command::Guess { day, part, value, verdict }) = parse(&["guess", "5", "2", "17729183", "too-high"]) else { panic!("failed to parse") };
+        assert_eq!((day, part, value.as_str(), verdict), (5, 2, "17729183", Verdict::TooHigh));
+        assert!(parse(&["guess", "5", "2", "17729183"]).is_err());
+        assert!(parse(&["guess", "5", "2", "17729183", "high"]).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let Ok(Command::Submit { day, part, answer, force }) = parse(&["submit", "7", "2", "--force", "250577259"]) else { panic!("failed to parse") };
+        assert_eq!((day, part, answer.as_deref(), force), (7, 2, Some("250577259"), true));
+        let Ok(Command::Submit { answer, force, .. }) = parse(&["submit", "7", "2"]) else { panic!("failed to parse") };
+        assert_eq!((answer, force), (None, false));
+        assert!(parse(&["submit", "7", "2", "1", "2"]).is_err());
+        let Ok(Command::Download { days }) = parse(&["download", "24", "25"]) else { panic!("failed to parse") };
+        assert_eq!(days, vec![24, 25]);
+    }
+
+    #[test]
+    fn test_encrypt() {
+        let Ok(Command::Encrypt { days }) = parse(&["encrypt", "7"]) else { panic!("failed to parse") };
+        assert_eq!(days, vec![7]);
+        let Ok(Command::Decrypt { days }) = parse(&["decrypt"]) else { panic!("failed to parse") };
+        assert_eq!(days.len(), 25);
+        assert!(parse(&["encrypt", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        let Ok(Command::New { day }) = parse(&["new", "20"]) else { panic!("failed to parse") };
+        assert_eq!(day, 20);
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "20", "24"]).is_err());
+        assert!(matches!(parse(&["dashboard"]), Ok(Command::Dashboard)));
+        assert!(parse(&["dashboard", "20"]).is_err());
+    }
+}
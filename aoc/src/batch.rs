@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use common::input::Source;
+
+use crate::solvers;
+use crate::watchdog::{self, Outcome};
+
+// Runs the solvers of one day against every input in a directory, like the personal inputs of several people,
+// to see whether they work with more than the one input they have been written for.
+// There are no correct answers to compare with, so this only shows which inputs fail.
+
+// returns whether all parts solved all inputs
+pub fn batch(day: u8, dir: &Path, timeout: Option<Duration>) -> Result<bool, String> {
+    let parts = (1..=2).filter(|&part| solvers::find_solved(day, part).is_some()).collect::<Vec<_>>();
+    if parts.is_empty() {
+        return Err(format!("there is no solver for day {day}"));
+    }
+    let files = list_inputs(dir)?;
+    if files.is_empty() {
+        return Err(format!("there are no inputs in '{}'", dir.display()));
+    }
+
+    let names = files.iter().map(|file| file.file_name().unwrap_or_default().to_string_lossy().to_string()).collect::<Vec<_>>();
+    let width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0).max("file".len());
+    let header = parts.iter().map(|part| format!("{:<20}", format!("part {part}"))).collect::<String>();
+    println!("{:<width$} {}", "file", header.trim_end());
+
+    let mut solved = true;
+    for (file, name) in files.iter().zip(names) {
+        let mut row = format!("{name:<width$}");
+        for &part in &parts {
+            let cell = match watchdog::run(day, part, &Source::Path(file.clone()), timeout, false) {
+                Outcome::Answer(answer) => answer,
+                Outcome::Error(err) => {
+                    solved = false;
+                    // parse errors come with the offending line, which doesn't fit into the table,
+                    // and start with the path of the file, which is in the first column already
+                    let err = err.lines().next().unwrap_or_default();
+                    err.strip_prefix(&format!("{}:", file.display())).unwrap_or(err).trim_start().to_string()
+                }
+                Outcome::TimedOut(candidate) => {
+                    solved = false;
+                    format!("timed out ({})", candidate.as_deref().unwrap_or("-"))
+                }
+            };
+            row += &format!(" {cell:<20}");
+        }
+        println!("{}", row.trim_end());
+    }
+    Ok(solved)
+}
+
+// the files directly in `dir` ordered by name, hidden ones are skipped
+fn list_inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(dir).map_err(|err| format!("failed to read '{}': {err}", dir.display()))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| format!("failed to read '{}': {err}", dir.display()))?.path();
+        let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_file() && !hidden {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::batch::list_inputs;
+
+    #[test]
+    fn test_list_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("subdir")).unwrap();
+        for name in ["bob", ".hidden", "alice"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let files = list_inputs(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(files, Ok(vec![dir.join("alice"), dir.join("bob")]));
+        assert!(list_inputs(&dir).is_err());
+    }
+}
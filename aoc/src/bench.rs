@@ -0,0 +1,227 @@
+use std::io::Write;
+use std::panic;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::input::{self, Source};
+
+use crate::solvers::{self, Solution, Solver};
+use crate::watchdog::{self, Outcome};
+
+// every benchmark run appends one line per part to this file in the puzzle root:
+//   <unix time> <day>-<part> <iterations> <parse µs> <solve µs>
+// the parse time is '-' for the solvers which have their input compiled in
+pub const HISTORY: &str = "bench-history";
+
+// a time counts as regression if it grew by more than this factor since the previous run,
+// short times vary too much between runs so it has to grow by a minimum as well
+const TOLERANCE: f64 = 1.2;
+const MIN_REGRESSION: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub iterations: u32,
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+// runs every part of the given days `iterations` times and reports the median times,
+// parts which take longer than `timeout` in a trial run are skipped
+pub fn bench(days: &[u8], iterations: u32, timeout: Option<Duration>) -> Result<(), String> {
+    let path = input::root().join(HISTORY);
+    let history = read(&path)?;
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+    println!("{:<4} {:>10} {:>10} {:>10}  notes", "day", "parse", "part 1", "part 2");
+    let mut records = Vec::new();
+    for &day in days {
+        let mut parse = Vec::new();
+        let mut parts = [None, None];
+        let mut notes = Vec::new();
+        for part in 1..=2 {
+            let Some(solver) = solvers::find_solved(day, part) else {
+                continue;
+            };
+            if let Some(note) = timeout.and_then(|timeout| trial(day, part, timeout)) {
+                notes.push(format!("part {part}: {note}"));
+                continue;
+            }
+            let solutions = match measure(solver, iterations) {
+                Ok(solutions) => solutions,
+                Err(err) => {
+                    notes.push(format!("part {part}: {err}"));
+                    continue;
+                }
+            };
+            let record = Record {
+                time,
+                day,
+                part,
+                iterations,
+                parse: median(solutions.iter().filter_map(|s| s.parse).collect()),
+                solve: median(solutions.iter().map(|s| s.solve).collect()).unwrap(),
+            };
+            parse.extend(solutions.iter().filter_map(|s| s.parse));
+            parts[part as usize - 1] = Some(record.solve);
+            if let Some(previous) = history.iter().rev().find(|r| r.day == day && r.part == part) {
+                notes.extend(regressions(previous, &record));
+            }
+            records.push(record);
+        }
+        if parts.iter().all(Option::is_none) && notes.is_empty() {
+            continue;
+        }
+        let [part1, part2] = parts.map(format_duration);
+        let row = format!("{day:<4} {:>10} {part1:>10} {part2:>10}  {}", format_duration(median(parse)), notes.join(", "));
+        println!("{}", row.trim_end());
+    }
+    append(&path, &records)
+}
+
+// solves the part once in a child process which is killed when it overruns, returns why to skip the part
+fn trial(day: u8, part: u8, timeout: Duration) -> Option<String> {
+    match watchdog::run(day, part, &Source::Input, Some(timeout), false) {
+        Outcome::Answer(_) => None,
+        // parse errors come with the offending line, which doesn't fit into the table
+        Outcome::Error(err) => Some(err.lines().next().unwrap_or_default().to_string()),
+        Outcome::TimedOut(_) => Some(format!("skipped, takes longer than {timeout:?}")),
+    }
+}
+
+fn measure(solver: &Solver, iterations: u32) -> Result<Vec<Solution>, String> {
+    let mut solutions = Vec::new();
+    for _ in 0..iterations {
+        match panic::catch_unwind(|| solver.time(&Source::Input)) {
+            Ok(Ok(solution)) => solutions.push(solution),
+            // parse errors come with the offending line, which doesn't fit into the table
+            Ok(Err(err)) => return Err(err.lines().next().unwrap_or_default().to_string()),
+            Err(_) => return Err("panicked".to_string()),
+        }
+    }
+    Ok(solutions)
+}
+
+fn median(mut durations: Vec<Duration>) -> Option<Duration> {
+    durations.sort();
+    durations.get(durations.len() / 2).copied()
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    duration.map_or("-".to_string(), |d| format!("{d:.2?}"))
+}
+
+// describes which times of `current` got slower than in `previous`
+fn regressions(previous: &Record, current: &Record) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut compare = |name: String, previous: Option<Duration>, current: Option<Duration>| {
+        if let (Some(previous), Some(current)) = (previous, current) {
+            if current.as_secs_f64() > previous.as_secs_f64() * TOLERANCE && current > previous + MIN_REGRESSION {
+                let percent = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                out.push(format!("REGRESSION {name} +{percent:.0}%"));
+            }
+        }
+    };
+    compare(format!("parse {}", current.part), previous.parse, current.parse);
+    compare(format!("part {}", current.part), Some(previous.solve), Some(current.solve));
+    out
+}
+
+pub fn read(path: &Path) -> Result<Vec<Record>, String> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+    parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Record>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|ln| !ln.is_empty() && !ln.starts_with('#'))
+        .map(Record::parse)
+        .collect()
+}
+
+fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+    let text: String = records.iter().map(|r| format!("{r}\n")).collect();
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+impl Record {
+    fn parse(ln: &str) -> Result<Self, String> {
+        let fields = ln.split_whitespace().collect::<Vec<_>>();
+        let [time, key, iterations, parse, solve] = fields[..] else {
+            return Err(format!("expected '<time> <day>-<part> <iterations> <parse µs> <solve µs>' but got '{ln}'"));
+        };
+        let (day, part) = key.split_once('-').ok_or_else(|| format!("invalid day-part '{key}'"))?;
+        let micros = |value: &str| value.parse().map(Duration::from_micros).map_err(|_| format!("invalid time '{value}'"));
+        Ok(Self {
+            time: time.parse().map_err(|_| format!("invalid timestamp '{time}'"))?,
+            day: day.parse().map_err(|_| format!("invalid day '{day}'"))?,
+            part: part.parse().map_err(|_| format!("invalid part '{part}'"))?,
+            iterations: iterations.parse().map_err(|_| format!("invalid number of iterations '{iterations}'"))?,
+            parse: if parse == "-" { None } else { Some(micros(parse)?) },
+            solve: micros(solve)?,
+        })
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let parse = self.parse.map_or("-".to_string(), |d| d.as_micros().to_string());
+        write!(f, "{} {}-{} {} {parse} {}", self.time, self.day, self.part, self.iterations, self.solve.as_micros())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{median, parse, regressions, Record};
+
+    fn record(parse: Option<u64>, solve: u64) -> Record {
+        Record { time: 1700000000, day: 7, part: 1, iterations: 10, parse: parse.map(Duration::from_micros), solve: Duration::from_micros(solve) }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let text = "# history\n1700000000 7-1 10 1500 2300\n\n1700000000 8-2 1 - 99\n";
+        let records = parse(text).unwrap();
+        assert_eq!(records[0], record(Some(1500), 2300));
+        assert_eq!(records[1].parse, None);
+        assert_eq!(records.iter().map(|r| r.to_string()).collect::<Vec<_>>(), vec!["1700000000 7-1 10 1500 2300", "1700000000 8-2 1 - 99"]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("1700000000 7-1 10 1500").is_err());
+        assert!(parse("1700000000 7 10 1500 2300").is_err());
+        assert!(parse("1700000000 7-1 10 fast 2300").is_err());
+    }
+
+    #[test]
+    fn test_regressions() {
+        assert!(regressions(&record(Some(10000), 10000), &record(Some(11000), 9000)).is_empty());
+        assert_eq!(regressions(&record(Some(10000), 10000), &record(Some(10000), 15000)), vec!["REGRESSION part 1 +50%"]);
+        assert_eq!(regressions(&record(Some(10000), 10000), &record(None, 10000)), Vec::<String>::new());
+        assert_eq!(regressions(&record(Some(1000), 10000), &record(Some(3000), 10000)), vec!["REGRESSION parse 1 +200%"]);
+        // too short to tell
+        assert!(regressions(&record(Some(100), 100), &record(Some(300), 900)).is_empty());
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = |ms: &[u64]| ms.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        assert_eq!(median(ms(&[3, 1, 2])), Some(Duration::from_millis(2)));
+        assert_eq!(median(ms(&[])), None);
+    }
+}
@@ -0,0 +1,136 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::input;
+
+// HTTP client for the Advent of Code website.
+// Requests are made with curl, which is installed almost everywhere and brings TLS along,
+// so the workspace doesn't need a dozen crates for a handful of requests.
+// Responses are kept in a cache below the puzzle root, except the puzzle inputs, and requests are spaced out,
+// the website asks to go easy on its servers.
+
+pub const URL: &str = "https://adventofcode.com";
+// overrides URL, for testing against a local server
+pub const URL_VAR: &str = "AOC_URL";
+// the value of the session cookie of a logged in browser, or the file in the puzzle root holding it
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".session";
+pub const CACHE_DIR: &str = ".cache";
+
+const USER_AGENT: &str = "aoc runner of a solutions repository for Advent of Code 2023, using curl";
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+// in the cache, holds the time of the last request in milliseconds since the epoch
+const LAST_REQUEST: &str = "last-request";
+
+pub struct Client {
+    url: String,
+    session: String,
+    cache: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(url: &str, session: &str, cache: PathBuf, min_interval: Duration) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), session: session.to_string(), cache, min_interval }
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let root = input::root();
+        let session = match std::env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => std::fs::read_to_string(root.join(SESSION_FILE)).map_err(|_| {
+                format!("no session token, set {SESSION_VAR} or save it in '{}'", root.join(SESSION_FILE).display())
+            })?,
+        };
+        let url = std::env::var(URL_VAR).unwrap_or(URL.to_string());
+        Ok(Self::new(&url, session.trim(), root.join(CACHE_DIR), MIN_INTERVAL))
+    }
+
+    // answered from the cache if `path` has been fetched before
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let cached = self.cache_path(path);
+        if let Ok(body) = std::fs::read_to_string(&cached) {
+            return Ok(body);
+        }
+        let body = self.request(path, &[])?;
+        save(&cached, &body)?;
+        Ok(body)
+    }
+
+    // bypasses the cache, for the puzzle input which is only saved encrypted if there is a key
+    pub fn fetch(&self, path: &str) -> Result<String, String> {
+        self.request(path, &[])
+    }
+
+    // posts the form, the response is saved in the cache as `cache_as` for reference only
+    pub fn post(&self, path: &str, form: &[(&str, &str)], cache_as: &str) -> Result<String, String> {
+        let body = self.request(path, form)?;
+        save(&self.cache_path(cache_as), &body)?;
+        Ok(body)
+    }
+
+    // drops the cached response of `path`, for pages which change
+    pub fn forget(&self, path: &str) {
+        let _ = std::fs::remove_file(self.cache_path(path));
+    }
+
+    // flat as a page can have subpages, like /2023/day/7 and /2023/day/7/input
+    fn cache_path(&self, path: &str) -> PathBuf {
+        self.cache.join(path.trim_start_matches('/').replace('/', "-"))
+    }
+
+    // a GET request without a form and a POST with one,
+    // the session cookie is passed to curl on stdin to keep it out of the process list
+    fn request(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.wait_for_turn()?;
+        let method = if form.is_empty() { "GET" } else { "POST" };
+        let mut command = Command::new("curl");
+        command.args(["--silent", "--show-error", "--user-agent", USER_AGENT, "--header", "@-", "--write-out", "\n%{http_code}"]);
+        for (key, value) in form {
+            command.args(["--data-urlencode".to_string(), format!("{key}={value}")]);
+        }
+        command.arg(format!("{}{path}", self.url));
+
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to run curl: {err}"))?;
+        let mut stdin = child.stdin.take().unwrap();
+        writeln!(stdin, "Cookie: session={}", self.session).map_err(|err| format!("failed to pass the session to curl: {err}"))?;
+        drop(stdin);
+        let output = child.wait_with_output().map_err(|err| format!("failed to run curl: {err}"))?;
+        if !output.status.success() {
+            return Err(format!("{method} {path} failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+        match status {
+            "200" => Ok(body.to_string()),
+            _ => Err(format!("{method} {path} failed with status {status}: {}", body.lines().next().unwrap_or_default())),
+        }
+    }
+
+    // sleeps until the last request, of any process using the same cache, is `min_interval` ago
+    fn wait_for_turn(&self) -> Result<(), String> {
+        let path = self.cache.join(LAST_REQUEST);
+        let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let last = std::fs::read_to_string(&path).ok().and_then(|millis| millis.trim().parse().ok()).map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.min_interval).checked_sub(now())) {
+            thread::sleep(wait);
+        }
+        save(&path, &now().as_millis().to_string())
+    }
+}
+
+fn save(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("failed to create '{}': {err}", dir.display()))?;
+    }
+    std::fs::write(path, text).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
@@ -0,0 +1,182 @@
+use std::path::Path;
+use std::time::Duration;
+
+use common::input;
+
+use crate::answers;
+use crate::bench::{self, Record};
+use crate::guesses::{self, Verdict};
+use crate::scaffold;
+use crate::site::YEAR;
+use crate::solvers;
+
+// Regenerates the star table in the README from what the day directories contain:
+// the solvers known to the runner, the correct answers on record and the times of the last benchmark.
+// Only the part of the README between the markers is replaced, it is appended if there are none.
+pub const README: &str = "README.md";
+
+const BEGIN: &str = "<!-- stars -->";
+const END: &str = "<!-- /stars -->";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Open,
+    // there is a solver but no correct answer to compare it with
+    Solved,
+    // the star has been earned but not with a solver of the runner, like 1-1 with a shell script
+    Answered,
+    // there is a solver and the correct answer is on record, `aoc verify` compares them
+    Verified,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Part {
+    pub status: Status,
+    // parse and solve time of the last benchmark
+    pub runtime: Option<Duration>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Day {
+    pub day: u8,
+    pub parts: [Part; 2],
+    // relative to the repository, like "08/rs_8-1"
+    pub crates: Vec<String>,
+}
+
+pub fn dashboard() -> Result<(), String> {
+    let repo = scaffold::repository();
+    let history = bench::read(&input::root().join(bench::HISTORY))?;
+    let has_solver = |day, part| solvers::find_solved(day, part).is_some();
+    let days = (1..=25).map(|day| collect(&repo, &input::day_dir(day), day, &history, has_solver)).collect::<Result<Vec<_>, _>>()?;
+    let table = render(&days);
+    print!("{table}");
+
+    let path = repo.join(README);
+    let readme = std::fs::read_to_string(&path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+    std::fs::write(&path, replace_section(&readme, &table)).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+fn collect(repo: &Path, day_dir: &Path, day: u8, history: &[Record], has_solver: impl Fn(u8, u8) -> bool) -> Result<Day, String> {
+    let answers = answers::read(day_dir)?;
+    let guesses = guesses::read(day_dir)?;
+    let parts = [1, 2].map(|part| {
+        let answered = answers.iter().any(|a| a.day == day && a.part == part)
+            || guesses.iter().any(|g| g.part == part && g.verdict == Verdict::Correct);
+        let status = match (has_solver(day, part), answered) {
+            (true, true) => Status::Verified,
+            (true, false) => Status::Solved,
+            (false, true) => Status::Answered,
+            (false, false) => Status::Open,
+        };
+        let runtime = match status {
+            Status::Solved | Status::Verified => history.iter().rev().find(|r| r.day == day && r.part == part).map(|r| r.parse.unwrap_or_default() + r.solve),
+            Status::Open | Status::Answered => None,
+        };
+        Part { status, runtime }
+    });
+    Ok(Day { day, parts, crates: crates(repo, day) })
+}
+
+// the crates of the day, the proc macro crates only serve the solvers
+fn crates(repo: &Path, day: u8) -> Vec<String> {
+    let dir = format!("{day:02}");
+    let Ok(entries) = std::fs::read_dir(repo.join(&dir)) else {
+        return Vec::new();
+    };
+    let mut crates = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with("macros"))
+        .map(|name| format!("{dir}/{name}"))
+        .collect::<Vec<_>>();
+    crates.sort();
+    crates
+}
+
+fn render(days: &[Day]) -> String {
+    let stars = days.iter().flat_map(|d| &d.parts).filter(|p| matches!(p.status, Status::Answered | Status::Verified)).count();
+    let mut out = format!("{stars} of 50 stars\n\n| Day | Part 1 | Part 2 | Solver |\n|----:|:-------|:-------|:-------|\n");
+    for day in days {
+        let [part1, part2] = day.parts.each_ref().map(cell);
+        let crates = day.crates.iter().map(|path| format!("[{}]({path})", path.rsplit('/').next().unwrap_or(path))).collect::<Vec<_>>();
+        let (number, crates) = (day.day, crates.join(" "));
+        let row = format!("| [{number}](https://adventofcode.com/{YEAR}/day/{number}) | {part1} | {part2} | {crates} |");
+        out += &row.replace("  ", " ");
+        out.push('\n');
+    }
+    out += "\n★ solved and the answer is on record, ☆ solved without a correct answer to verify it against.\n";
+    out += "The times are parsing and solving in the last `aoc bench` on this machine.\n";
+    out
+}
+
+fn cell(part: &Part) -> String {
+    let runtime = part.runtime.map_or(String::new(), |d| format!(" {d:.2?}"));
+    match part.status {
+        Status::Open => String::new(),
+        Status::Solved => format!("☆{runtime}"),
+        Status::Answered => "★ without solver".to_string(),
+        Status::Verified => format!("★{runtime}"),
+    }
+}
+
+fn replace_section(readme: &str, section: &str) -> String {
+    let section = format!("{BEGIN}\n{section}{END}");
+    match (readme.find(BEGIN), readme.find(END)) {
+        (Some(begin), Some(end)) if begin < end => format!("{}{section}{}", &readme[..begin], &readme[end + END.len()..]),
+        _ => format!("{}\n\n{section}\n", readme.trim_end()),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::Record;
+    use crate::dashboard::{cell, collect, render, replace_section, Day, Part, Status};
+
+    #[test]
+    fn test_collect() {
+        let dir = std::env::temp_dir().join(format!("aoc-dashboard-{}", std::process::id()));
+        for (file, text) in [("01/rs/Cargo.toml", ""), ("01/correct-answer.txt", "1-1: 55607\n1-2: 55291\n"), ("07/rs/Cargo.toml", ""), ("07/correct-solution.txt", "7-1 252295678\n"), ("20/exp", ""), ("20/task", "")] {
+            std::fs::create_dir_all(dir.join(file).parent().unwrap()).unwrap();
+            std::fs::write(dir.join(file), text).unwrap();
+        }
+        let history = [Record { time: 1700000000, day: 7, part: 2, iterations: 5, parse: Some(Duration::from_micros(500)), solve: Duration::from_micros(1500) }];
+        // as it has been before day 1 got a solver for part one
+        let has_solver = |day, part| matches!((day, part), (1, 2) | (7, _));
+        let day = |day: u8| collect(&dir, &dir.join(format!("{day:02}")), day, &history, has_solver).unwrap();
+        let (day1, day7, day20) = (day(1), day(7), day(20));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // part 1 of day 1 has been solved with a shell script
+        assert_eq!(day1.parts.each_ref().map(|p| p.status), [Status::Answered, Status::Verified]);
+        assert_eq!(day1.crates, vec!["01/rs"]);
+        assert_eq!(day7.parts, [Part { status: Status::Verified, runtime: None }, Part { status: Status::Solved, runtime: Some(Duration::from_millis(2)) }]);
+        assert_eq!(day20, Day { day: 20, parts: [Part { status: Status::Open, runtime: None }, Part { status: Status::Open, runtime: None }], crates: vec![] });
+    }
+
+    #[test]
+    fn test_render() {
+        let part = |status, ms: Option<u64>| Part { status, runtime: ms.map(Duration::from_millis) };
+        assert_eq!(cell(&part(Status::Verified, Some(12))), "★ 12.00ms");
+        assert_eq!(cell(&part(Status::Solved, None)), "☆");
+        let days = [
+            Day { day: 8, parts: [part(Status::Verified, None), part(Status::Solved, None)], crates: vec!["08/rs_8-1".to_string(), "08/rs_8-2".to_string()] },
+            Day { day: 20, parts: [part(Status::Open, None), part(Status::Open, None)], crates: vec![] },
+        ];
+        let table = render(&days);
+        assert!(table.starts_with("1 of 50 stars\n"));
+        assert!(table.contains("\n| [8](https://adventofcode.com/2023/day/8) | ★ | ☆ | [rs_8-1](08/rs_8-1) [rs_8-2](08/rs_8-2) |\n"));
+        assert!(table.contains("\n| [20](https://adventofcode.com/2023/day/20) | | | |\n"));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "intro\n\n<!-- stars -->\nold\n<!-- /stars -->\n\nmore\n";
+        assert_eq!(replace_section(readme, "new\n"), "intro\n\n<!-- stars -->\nnew\n<!-- /stars -->\n\nmore\n");
+        assert_eq!(replace_section("intro\n", "new\n"), "intro\n\n<!-- stars -->\nnew\n<!-- /stars -->\n");
+    }
+}
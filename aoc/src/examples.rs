@@ -0,0 +1,106 @@
+use std::path::Path;
+
+// every day directory may contain a manifest named "examples" which lists the example
+// files of that day together with the answer the puzzle text gives for them, one per line:
+//   <part> <example file> <answer>
+// empty lines and lines starting with '#' are ignored
+pub const FILE_NAME: &str = "examples";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub name: String,
+    pub answer: String,
+}
+
+pub fn read(day_dir: &Path) -> Result<Vec<Example>, String> {
+    let path = day_dir.join(FILE_NAME);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(&path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+    parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Example>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|ln| !ln.is_empty() && !ln.starts_with('#'))
+        .map(Example::parse)
+        .collect()
+}
+
+pub fn write(day_dir: &Path, examples: &[Example]) -> Result<(), String> {
+    let path = day_dir.join(FILE_NAME);
+    let text: String = examples.iter().map(|e| format!("{} {} {}\n", e.part, e.name, e.answer)).collect();
+    std::fs::write(&path, text).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+impl Example {
+    fn parse(ln: &str) -> Result<Self, String> {
+        let mut fields = ln.split_whitespace();
+        let (Some(part), Some(name), Some(answer), None) = (fields.next(), fields.next(), fields.next(), fields.next()) else {
+            return Err(format!("expected '<part> <example file> <answer>' but got '{ln}'"));
+        };
+        Ok(Self {
+            part: part.parse().map_err(|_| format!("invalid part '{part}'"))?,
+            name: name.to_string(),
+            answer: answer.to_string(),
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use common::input::{self, Source};
+
+    use crate::examples::{parse, read, Example};
+    use crate::solvers::{self, Solve};
+
+    fn example(part: u8, name: &str, answer: &str) -> Example {
+        Example { part, name: name.to_string(), answer: answer.to_string() }
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = "# day 10\n1 exp_easy 4\n\n2   exp2_gap   4\n";
+        assert_eq!(parse(text), Ok(vec![example(1, "exp_easy", "4"), example(2, "exp2_gap", "4")]));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("1 exp").is_err());
+        assert!(parse("1 exp 42 43").is_err());
+        assert!(parse("one exp 42").is_err());
+    }
+
+    // runs every solver against every example listed in the manifests
+    #[test]
+    fn test_examples() {
+        let mut failures = Vec::new();
+        for day in 1..=25 {
+            let path = input::day_dir(day);
+            for example in read(&path).unwrap() {
+                assert!(path.join(&example.name).is_file(), "day {day} lists missing example '{}'", example.name);
+                let Some(solver) = solvers::find_solved(day, example.part) else {
+                    continue;
+                };
+                if let Solve::Embedded(_) = solver.solve {
+                    println!("skipping {day}-{} {}: input is compiled in", example.part, example.name);
+                    continue;
+                }
+                let actual = panic::catch_unwind(|| solver.run(&Source::Example(example.name.clone())));
+                match actual {
+                    Ok(Ok(actual)) if actual == example.answer => {}
+                    Ok(Ok(actual)) => failures.push(format!("{day}-{} {}: expected {}, got {actual}", example.part, example.name, example.answer)),
+                    Ok(Err(err)) => failures.push(format!("{day}-{} {}: {err}", example.part, example.name)),
+                    Err(_) => failures.push(format!("{day}-{} {}: panicked", example.part, example.name)),
+                }
+            }
+        }
+        assert!(failures.is_empty(), "failed examples:\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use common::{crypt, input};
+
+use crate::examples::{self, Example};
+
+// the saved puzzle text has been named "task.txt" for the first days and "task" later on
+pub const TASK_FILES: &[&str] = &["task.txt", "task"];
+const PART_TWO: &str = "--- Part Two ---";
+
+// what the puzzle text of one part says about its example
+#[derive(Debug, PartialEq, Eq)]
+pub struct Extracted {
+    pub part: u8,
+    pub block: String,
+    pub answer: String,
+}
+
+// writes the example manifest of every given day which has a puzzle text,
+// existing manifests are only replaced with `force` since they may have been edited by hand
+pub fn extract_days(days: &[u8], force: bool) -> Result<(), String> {
+    for &day in days {
+        let day_dir = input::day_dir(day);
+        let Some(task) = TASK_FILES.iter().map(|name| day_dir.join(name)).find(|path| path.is_file()) else {
+            continue;
+        };
+        let manifest = day_dir.join(examples::FILE_NAME);
+        if manifest.exists() && !force {
+            println!("{day:02}: keeping {} with {} examples", manifest.display(), examples::read(&day_dir)?.len());
+            continue;
+        }
+
+        let text = std::fs::read_to_string(&task).map_err(|err| format!("failed to read '{}': {err}", task.display()))?;
+        let input = crypt::read(&day_dir.join(input::INPUT)).ok();
+        let mut entries = Vec::new();
+        for extracted in extract(&text, input.as_deref()) {
+            let name = example_file(&day_dir, &extracted.block)?;
+            println!("{day:02}: part {} {name} {}", extracted.part, extracted.answer);
+            entries.push(Example { part: extracted.part, name, answer: extracted.answer });
+        }
+        if entries.is_empty() {
+            println!("{day:02}: no examples found");
+            continue;
+        }
+        examples::write(&day_dir, &entries)?;
+    }
+    Ok(())
+}
+
+// returns the name of the example file holding `block`, saving it under a new name if there is none yet
+fn example_file(day_dir: &Path, block: &str) -> Result<String, String> {
+    let mut names = std::fs::read_dir(day_dir)
+        .map_err(|err| format!("failed to read '{}': {err}", day_dir.display()))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("exp"))
+        .collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        if std::fs::read_to_string(day_dir.join(&name)).is_ok_and(|text| text.trim_end() == block) {
+            return Ok(name);
+        }
+    }
+
+    let name = (1..)
+        .map(|i| if i == 1 { "exp".to_string() } else { format!("exp{i}") })
+        .find(|name| !day_dir.join(name).exists())
+        .unwrap();
+    let path = day_dir.join(&name);
+    std::fs::write(&path, format!("{block}\n")).map_err(|err| format!("failed to write '{}': {err}", path.display()))?;
+    Ok(name)
+}
+
+// `input` is the real puzzle input, if there is one, blocks that use characters it doesn't contain
+// are illustrations rather than examples
+pub fn extract(text: &str, input: Option<&str>) -> Vec<Extracted> {
+    let (one, two) = text.split_once(PART_TWO).unwrap_or((text, ""));
+    let mut out = Vec::new();
+    let first = extract_part(1, &paragraphs(one), input, None);
+    let block = first.as_ref().map(|e| e.block.clone());
+    out.extend(first);
+    out.extend(extract_part(2, &paragraphs(two), input, block));
+    out
+}
+
+// the answer is the last number of the last paragraph before the question which talks about the example,
+// the example is the last block newly introduced as one before that,
+// falling back to the first block of part one or the example of part one for part two
+fn extract_part(part: u8, paragraphs: &[String], input: Option<&str>, previous: Option<String>) -> Option<Extracted> {
+    let question = paragraphs.iter().rposition(|p| !is_block(p) && p.contains('?'))?;
+    let answers = paragraphs[..question]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, p)| !is_block(p))
+        .filter_map(|(i, p)| last_number(p).map(|n| (i, n, p.to_lowercase().contains("example"))))
+        .collect::<Vec<_>>();
+    let (index, answer, _) = answers.iter().find(|(_, _, example)| *example).or(answers.first())?.clone();
+
+    let is_example = |p: &str| is_block(p) && input.is_none_or(|input| looks_like(p, input));
+    let introduces = |p: &str| {
+        let p = p.to_lowercase();
+        p.contains("example") && !p.contains("again") && !p.contains("above")
+    };
+    // sometimes the answer is given right before the example
+    let introduced = (1..=index + 1).rev().find(|&i| is_example(&paragraphs[i]) && introduces(&paragraphs[i - 1]));
+    let block = match introduced {
+        Some(i) => paragraphs[i].clone(),
+        None if part == 1 => paragraphs[..index].iter().find(|p| is_example(p))?.clone(),
+        None => previous?,
+    };
+    Some(Extracted { part, block, answer })
+}
+
+// consecutive blocks are joined as examples may contain empty lines themselves
+fn paragraphs(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for p in text.split("\n\n").map(|p| p.trim_matches('\n')).filter(|p| !p.trim().is_empty()) {
+        match out.last_mut() {
+            Some(last) if is_block(last) && is_block(p) => {
+                last.push_str("\n\n");
+                last.push_str(p);
+            }
+            _ => out.push(p.to_string()),
+        }
+    }
+    out
+}
+
+// blocks are copied verbatim from the puzzle, while lists are indented and prose consists of sentences
+fn is_block(paragraph: &str) -> bool {
+    if paragraph.starts_with(char::is_whitespace) || paragraph.starts_with("---") {
+        return false;
+    }
+    let single = paragraph.lines().count() == 1;
+    !paragraph.lines().any(|ln| {
+        let ln = ln.trim_end();
+        let words = ln.split_whitespace().count();
+        (words >= 4 || single && words >= 2) && ln.ends_with(['.', ':', '?', '!', ')', '"']) && ln.contains(|c: char| c.is_lowercase())
+    })
+}
+
+// illustrations use characters that don't appear in the input or are indented,
+// examples may only use other letters and digits than the input
+fn looks_like(block: &str, input: &str) -> bool {
+    let kind = |c: char| if c.is_ascii_alphanumeric() { 'a' } else { c };
+    let kinds = input.chars().map(kind).collect::<HashSet<_>>();
+    let indented = |text: &str| text.lines().any(|ln| ln.starts_with(char::is_whitespace));
+    block.chars().all(|c| c.is_whitespace() || kinds.contains(&kind(c))) && (indented(input) || !indented(block))
+}
+
+// ignores numbers in parentheses as they are mostly explanations like "288 (4 * 8 * 9)"
+fn last_number(paragraph: &str) -> Option<String> {
+    let mut depth = 0;
+    let mut text = String::new();
+    for c in paragraph.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if depth == 0 => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '-'))
+        .rfind(|word| word.parse::<i64>().is_ok())
+        .map(str::to_string)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::extract::{extract, is_block, last_number, Extracted};
+
+    const TASK: &str = "\
+--- Day 6: Wait For It ---
+
+As part of signing up, you get a sheet of paper.
+
+For example:
+
+Time:      7  15   30
+Distance:  9  40  200
+
+This document describes three races:
+
+    The first race lasts 7 milliseconds.
+    The second race lasts 15 milliseconds.
+
+In this example, if you multiply these values together, you get 288 (4 * 8 * 9).
+
+Determine the number of ways you could beat the record in each race. What do you get if you multiply these numbers together?
+
+--- Part Two ---
+
+So, the example from before:
+
+Time:      71530
+Distance:  940200
+
+In this example, you could beat the record in a total of 71503 ways!
+
+How many ways can you beat the record in this one much longer race?
+";
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(TASK, None),
+            vec![
+                Extracted { part: 1, block: "Time:      7  15   30\nDistance:  9  40  200".to_string(), answer: "288".to_string() },
+                Extracted { part: 2, block: "Time:      71530\nDistance:  940200".to_string(), answer: "71503".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_reuses_example_of_part_one() {
+        let task = "For example:\n\n1\n2\n\nThe sum is 3.\n\nWhat is the sum?\n\n--- Part Two ---\n\nNow the product is 2.\n\nWhat is the product?\n";
+        let extracted = extract(task, None);
+        assert_eq!(extracted.len(), 2);
+        assert_eq!((extracted[1].block.as_str(), extracted[1].answer.as_str()), ("1\n2", "2"));
+    }
+
+    #[test]
+    fn test_is_block() {
+        assert!(is_block("467..114..\n...*......"));
+        assert!(is_block("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"));
+        assert!(is_block("seed-to-soil map:\n50 98 2"));
+        assert!(!is_block("Here is an example engine schematic:"));
+        assert!(!is_block("For example:"));
+        assert!(!is_block("    | is a vertical pipe connecting north and south."));
+    }
+
+    #[test]
+    fn test_last_number() {
+        assert_eq!(last_number("their sum is 4361."), Some("4361".to_string()));
+        assert_eq!(last_number("you get 288 (4 * 8 * 9)."), Some("288".to_string()));
+        assert_eq!(last_number("no numbers here"), None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+// Every answer submitted on the website is logged in the `guesses` file of its day together with the verdict:
+//   5-2 17729183 too-high
+// so answers which can't be right are noticed before they are submitted.
+pub const FILE: &str = "guesses";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    // the website doesn't always say in which direction
+    Wrong,
+    Correct,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub value: String,
+    pub verdict: Verdict,
+}
+
+pub fn read(day_dir: &Path) -> Result<Vec<Guess>, String> {
+    let path = day_dir.join(FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(&path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+    parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Guess>, String> {
+    text.lines().map(str::trim).filter(|ln| !ln.is_empty() && !ln.starts_with('#')).map(Guess::parse).collect()
+}
+
+pub fn append(day_dir: &Path, guess: &Guess) -> Result<(), String> {
+    let path = day_dir.join(FILE);
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{guess}"))
+        .map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+// the range the answer of the part has to be in according to the guesses which were too low or too high,
+// both bounds are exclusive
+pub fn bounds(guesses: &[Guess], part: u8) -> (Option<i128>, Option<i128>) {
+    let values = |verdict| guesses.iter().filter(move |g| g.part == part && g.verdict == verdict).filter_map(|g| g.value.parse::<i128>().ok());
+    (values(Verdict::TooLow).max(), values(Verdict::TooHigh).min())
+}
+
+// describes why `answer` can't be the answer of the part according to the guesses
+pub fn check(guesses: &[Guess], part: u8, answer: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(guess) = guesses.iter().find(|g| g.part == part && g.value == answer && g.verdict != Verdict::Correct) {
+        out.push(format!("{answer} has been submitted before and was {}", guess.verdict));
+    }
+    let Ok(value) = answer.parse::<i128>() else {
+        return out;
+    };
+    let (low, high) = bounds(guesses, part);
+    if let Some(low) = low.filter(|&low| value <= low) {
+        out.push(format!("{answer} is not above {low}, which was too low"));
+    }
+    if let Some(high) = high.filter(|&high| value >= high) {
+        out.push(format!("{answer} is not below {high}, which was too high"));
+    }
+    out
+}
+
+impl Guess {
+    fn parse(ln: &str) -> Result<Self, String> {
+        let fields = ln.split_whitespace().collect::<Vec<_>>();
+        let [key, value, verdict] = fields[..] else {
+            return Err(format!("expected '<day>-<part> <value> <verdict>' but got '{ln}'"));
+        };
+        let (day, part) = key.split_once('-').ok_or_else(|| format!("invalid day-part '{key}'"))?;
+        Ok(Self {
+            day: day.parse().map_err(|_| format!("invalid day '{day}'"))?,
+            part: part.parse().map_err(|_| format!("invalid part '{part}'"))?,
+            value: value.to_string(),
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+impl fmt::Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {} {}", self.day, self.part, self.value, self.verdict)
+    }
+}
+
+impl std::str::FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(verdict: &str) -> Result<Self, String> {
+        match verdict {
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            "correct" => Ok(Self::Correct),
+            _ => Err(format!("invalid verdict '{verdict}', expected too-high, too-low, wrong or correct")),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::Wrong => "wrong",
+            Self::Correct => "correct",
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::guesses::{bounds, check, parse, Guess, Verdict};
+
+    fn guesses() -> Vec<Guess> {
+        parse("# day 5\n5-2 20000000 too-high\n5-2 17000000 too-low\n5-2 18000000 too-high\n5-2 17729183 wrong\n5-1 1 too-low\n").unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let guesses = guesses();
+        assert_eq!(guesses[0], Guess { day: 5, part: 2, value: "20000000".to_string(), verdict: Verdict::TooHigh });
+        assert_eq!(guesses.iter().map(|g| g.to_string()).nth(3), Some("5-2 17729183 wrong".to_string()));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("5-2 20000000").is_err());
+        assert!(parse("5-2 20000000 too-large").is_err());
+        assert!(parse("5 20000000 wrong").is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(bounds(&guesses(), 2), (Some(17000000), Some(18000000)));
+        assert_eq!(bounds(&guesses(), 1), (Some(1), None));
+    }
+
+    #[test]
+    fn test_check() {
+        let guesses = guesses();
+        assert!(check(&guesses, 2, "17729182").is_empty());
+        assert_eq!(check(&guesses, 2, "17729183"), vec!["17729183 has been submitted before and was wrong"]);
+        assert_eq!(check(&guesses, 2, "17000000"), vec![
+            "17000000 has been submitted before and was too-low",
+            "17000000 is not above 17000000, which was too low",
+        ]);
+        assert_eq!(check(&guesses, 2, "19000000"), vec!["19000000 is not below 18000000, which was too high"]);
+        assert!(check(&guesses, 1, "abc").is_empty());
+    }
+}
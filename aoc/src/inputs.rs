@@ -0,0 +1,138 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+
+use common::crypt::{self, Key};
+use common::input;
+
+// Converts the puzzle inputs of the day directories between plain and encrypted files,
+// the solvers read either since the input module decrypts transparently.
+
+// encrypts the plain inputs of the days, with a new key if there is none yet
+pub fn encrypt_days(days: &[u8]) -> Result<(), String> {
+    let key = match crypt::load_key()? {
+        Some(key) => key,
+        None => create_key()?,
+    };
+    for &day in days {
+        let path = input::resolve(day, input::INPUT);
+        if encrypt_file(&key, &path)? {
+            println!("{day:02}: encrypted {}", crypt::encrypted_path(&path).display());
+        }
+    }
+    Ok(())
+}
+
+pub fn decrypt_days(days: &[u8]) -> Result<(), String> {
+    let key = crypt::load_key()?.ok_or_else(|| {
+        format!("there is no key, set {} or save it in '{}'", crypt::KEY_VAR, crypt::key_path().display())
+    })?;
+    for &day in days {
+        let path = input::resolve(day, input::INPUT);
+        if decrypt_file(&key, &path)? {
+            println!("{day:02}: decrypted {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn create_key() -> Result<Key, String> {
+    let key = Key::generate();
+    let path = crypt::key_path();
+    write_key(&path, &key)?;
+    eprintln!("created a new key in '{}', keep a copy of it, the inputs can't be decrypted without it", path.display());
+    Ok(key)
+}
+
+// only the user may read the key, an existing file is never overwritten
+fn write_key(path: &Path, key: &Key) -> Result<(), String> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).map_err(|err| format!("failed to create '{}': {err}", path.display()))?;
+    file.write_all(key.to_hex().as_bytes()).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+// replaces `path` by <path>.enc, returns false if there is no plain file
+fn encrypt_file(key: &Key, path: &Path) -> Result<bool, String> {
+    if !path.is_file() {
+        return Ok(false);
+    }
+    let plain = std::fs::read(path).map_err(|err| format!("failed to read '{}': {err}", path.display()))?;
+    write(&crypt::encrypted_path(path), &crypt::encrypt(key, &plain))?;
+    remove(path)?;
+    Ok(true)
+}
+
+// replaces <path>.enc by `path`, returns false if there is no encrypted file
+fn decrypt_file(key: &Key, path: &Path) -> Result<bool, String> {
+    let encrypted = crypt::encrypted_path(path);
+    if !encrypted.is_file() {
+        return Ok(false);
+    }
+    let data = std::fs::read(&encrypted).map_err(|err| format!("failed to read '{}': {err}", encrypted.display()))?;
+    let plain = crypt::decrypt(key, &data).map_err(|err| format!("{}: {err}", encrypted.display()))?;
+    write(path, &plain)?;
+    remove(&encrypted)?;
+    Ok(true)
+}
+
+fn write(path: &Path, data: &[u8]) -> Result<(), String> {
+    std::fs::write(path, data).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+fn remove(path: &Path) -> Result<(), String> {
+    std::fs::remove_file(path).map_err(|err| format!("failed to remove '{}': {err}", path.display()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use common::crypt::{self, Key};
+
+    use crate::inputs::{decrypt_file, encrypt_file, write_key};
+
+    #[test]
+    fn test_encrypt_and_decrypt_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input");
+        std::fs::write(&path, "32T3K 765\n").unwrap();
+        let key = Key::generate();
+
+        let encrypted = encrypt_file(&key, &path);
+        let plain_after_encrypt = path.exists();
+        let again = encrypt_file(&key, &path);
+        let wrong_key = decrypt_file(&Key::generate(), &path);
+        let decrypted = decrypt_file(&key, &path);
+        let text = std::fs::read_to_string(&path);
+        let encrypted_after_decrypt = crypt::encrypted_path(&path).exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!((encrypted, plain_after_encrypt, again), (Ok(true), false, Ok(false)));
+        assert!(wrong_key.is_err());
+        assert_eq!((decrypted, text.unwrap().as_str(), encrypted_after_decrypt), (Ok(true), "32T3K 765\n", false));
+    }
+
+    #[test]
+    fn test_write_key() {
+        let path = std::env::temp_dir().join(format!("aoc-input-key-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let key = Key::generate();
+        let written = write_key(&path, &key);
+        let again = write_key(&path, &Key::generate());
+        let hex = std::fs::read_to_string(&path).unwrap();
+        #[cfg(unix)]
+        let mode = std::os::unix::fs::PermissionsExt::mode(&std::fs::metadata(&path).unwrap().permissions());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(written, Ok(()));
+        assert!(again.is_err());
+        assert_eq!(Key::from_hex(&hex), Ok(key));
+        #[cfg(unix)]
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -0,0 +1,59 @@
+// The JSON documents written by `aoc run --json`, one per line:
+//   {"day":17,"part":1,"answer":"1023","parse_us":812,"solve_us":5230417,"stats":{"states_explored":815239}}
+// The answer is a string because some don't fit into the numbers JSON readers support,
+// parse_us is null for the solvers which have their input compiled in.
+
+use crate::solvers::Solution;
+
+pub fn solution(day: u8, part: u8, solution: &Solution) -> String {
+    let parse = solution.parse.map_or("null".to_string(), |d| d.as_micros().to_string());
+    let stats = solution.stats.iter().map(|(name, value)| format!("{}:{value}", string(name))).collect::<Vec<_>>();
+    format!(
+        r#"{{"day":{day},"part":{part},"answer":{},"parse_us":{parse},"solve_us":{},"stats":{{{}}}}}"#,
+        string(&solution.answer),
+        solution.solve.as_micros(),
+        stats.join(","),
+    )
+}
+
+fn string(text: &str) -> String {
+    let mut out = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::json::{solution, string};
+    use crate::solvers::Solution;
+
+    #[test]
+    fn test_solution() {
+        let s = Solution {
+            answer: "2318".to_string(),
+            parse: Some(Duration::from_micros(120)),
+            solve: Duration::from_millis(3),
+            stats: vec![("hikes", 42), ("dead_ends", 7)],
+        };
+        assert_eq!(solution(23, 1, &s), r#"{"day":23,"part":1,"answer":"2318","parse_us":120,"solve_us":3000,"stats":{"hikes":42,"dead_ends":7}}"#);
+        let s = Solution { answer: "19631".to_string(), parse: None, solve: Duration::from_micros(5), stats: vec![] };
+        assert_eq!(solution(19, 1, &s), r#"{"day":19,"part":1,"answer":"19631","parse_us":null,"solve_us":5,"stats":{}}"#);
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("a \"b\"\\\n\t"), r#""a \"b\"\\\n\u0009""#);
+    }
+}
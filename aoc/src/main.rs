@@ -0,0 +1,206 @@
+mod answers;
+mod args;
+mod batch;
+mod bench;
+mod client;
+mod dashboard;
+mod examples;
+mod extract;
+mod guesses;
+mod inputs;
+mod json;
+mod scaffold;
+mod site;
+mod solvers;
+mod verify;
+mod watchdog;
+
+use std::time::Duration;
+
+use common::input::Source;
+
+use args::{Command, USAGE};
+
+fn main() {
+    let command = Command::parse(std::env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    });
+
+    match command {
+        Command::Run { day, part, input, timeout, json, verbosity } => {
+            common::log::enable(&verbosity);
+            run(day, part, input, timeout, json)
+        }
+        Command::Batch { day, dir, timeout } => {
+            match batch::batch(day, &dir, timeout) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Extract { days, force } => {
+            if let Err(err) = extract::extract_days(&days, force) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Verify { days, timeout } => {
+            match verify::verify(&days, timeout) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Bench { days, iterations, timeout } => {
+            if let Err(err) = bench::bench(&days, iterations, timeout) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Download { days } => {
+            if let Err(err) = download(&days) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Submit { day, part, answer, force } => {
+            if let Err(err) = submit(day, part, answer, force) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Encrypt { days } => {
+            if let Err(err) = inputs::encrypt_days(&days) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Decrypt { days } => {
+            if let Err(err) = inputs::decrypt_days(&days) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::New { day } => {
+            if let Err(err) = scaffold::new_day(&scaffold::repository(), day) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Dashboard => {
+            if let Err(err) = dashboard::dashboard() {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Guess { day, part, value, verdict } => {
+            if let Err(err) = guess(guesses::Guess { day, part, value, verdict }) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn run(day: u8, part: u8, input: Source, timeout: Option<Duration>, json: bool) {
+    let solver = solvers::find(day, part).unwrap_or_else(|| {
+        eprintln!("there is no solver for day {day} part {part}");
+        std::process::exit(1);
+    });
+    if let Some(timeout) = timeout {
+        return run_watched(day, part, input, timeout);
+    }
+    let output = match json {
+        true => solver.time(&input).map(|solution| (json::solution(day, part, &solution), solution.answer)),
+        false => solver.run(&input).map(|answer| (format!("{day}-{part}: {answer}"), answer)),
+    };
+    match output {
+        Ok((output, answer)) => {
+            println!("{output}");
+            check_guesses(day, part, &input, &answer);
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+// runs the solver in a child process, which is killed when it takes longer than `timeout`
+fn run_watched(day: u8, part: u8, input: Source, timeout: Duration) {
+    match watchdog::run(day, part, &input, Some(timeout), true) {
+        watchdog::Outcome::Answer(answer) => {
+            println!("{day}-{part}: {answer}");
+            check_guesses(day, part, &input, &answer);
+        }
+        // the child has written its errors to stderr already
+        watchdog::Outcome::Error(_) => std::process::exit(1),
+        watchdog::Outcome::TimedOut(candidate) => {
+            match candidate {
+                Some(candidate) => println!("{day}-{part}: timed out after {timeout:?}, best candidate {candidate}"),
+                None => println!("{day}-{part}: timed out after {timeout:?} without a candidate"),
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+// warns about answers which can't be right according to the guesses submitted before,
+// those are about the puzzle input, other inputs have different answers
+fn check_guesses(day: u8, part: u8, input: &Source, answer: &str) {
+    if *input != Source::Input {
+        return;
+    }
+    match guesses::read(&common::input::day_dir(day)) {
+        Ok(guesses) => {
+            for warning in guesses::check(&guesses, part, answer) {
+                eprintln!("warning: {day}-{part}: {warning}");
+            }
+        }
+        Err(err) => eprintln!("warning: {err}"),
+    }
+}
+
+fn guess(guess: guesses::Guess) -> Result<(), String> {
+    let dir = common::input::day_dir(guess.day);
+    guesses::append(&dir, &guess)?;
+    let (low, high) = guesses::bounds(&guesses::read(&dir)?, guess.part);
+    if low.is_some() || high.is_some() {
+        let format = |bound: Option<i128>| bound.map_or("?".to_string(), |b| b.to_string());
+        println!("{}-{} is between {} and {}", guess.day, guess.part, format(low), format(high));
+    }
+    Ok(())
+}
+
+fn download(days: &[u8]) -> Result<(), String> {
+    let client = client::Client::from_env()?;
+    let key = common::crypt::load_key()?;
+    for &day in days {
+        site::download(&client, day, &common::input::day_dir(day), key.as_ref())?;
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, answer: Option<String>, force: bool) -> Result<(), String> {
+    let client = client::Client::from_env()?;
+    let value = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = solvers::find(day, part).ok_or(format!("there is no solver for day {day} part {part}"))?;
+            solver.run(&Source::Input)?
+        }
+    };
+    println!("submitting {value} for {day}-{part}");
+    let guess = guesses::Guess { day, part, value, verdict: guesses::Verdict::Wrong };
+    let verdict = site::submit(&client, &guess, &common::input::day_dir(day), force)?;
+    println!("{day}-{part}: {verdict}");
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+// Creates the crate of a new day from the files in the template directory of the repository,
+// every "{{day}}" in them is replaced by the number of the day.
+// The crate is added to the workspace and its parts to the solvers of the runner as Solve::Unsolved,
+// which fails when it is run and is skipped by `aoc verify`, the example tests and the dashboard,
+// otherwise the stubs of the template would count as answers. It is replaced by hand once the part is solved.
+pub const TEMPLATE_DIR: &str = "template";
+
+const PLACEHOLDER: &str = "{{day}}";
+
+// the crates live in the repository, which is not necessarily the puzzle root
+pub fn repository() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+pub fn new_day(repo: &Path, day: u8) -> Result<(), String> {
+    let day_dir = repo.join(format!("{day:02}"));
+    if day_dir.join("rs").exists() {
+        return Err(format!("'{}' exists already", day_dir.join("rs").display()));
+    }
+
+    // the edits are prepared first, so nothing is written if one of the files doesn't look as expected
+    let workspace = repo.join("Cargo.toml");
+    let workspace_text = insert(&read(&workspace)?, &[format!("    \"{day:02}/rs\",")], day, member_day)
+        .map_err(|err| format!("{}: {err}", workspace.display()))?;
+    let runner = repo.join("aoc/Cargo.toml");
+    let runner_text = insert(&read(&runner)?, &[format!("rs_{day} = {{ path = \"../{day:02}/rs\" }}")], day, dependency_day)
+        .map_err(|err| format!("{}: {err}", runner.display()))?;
+    let solvers = repo.join("aoc/src/solvers.rs");
+    let unsolved = (1..=2).map(|part| format!("    Solver {{ day: {day}, part: {part}, solve: Solve::Unsolved }},")).collect::<Vec<_>>();
+    let solvers_text = insert(&read(&solvers)?, &unsolved, day, solver_day)
+        .map_err(|err| format!("{}: {err}", solvers.display()))?;
+
+    for file in copy_template(&repo.join(TEMPLATE_DIR), &day_dir, day)? {
+        println!("created {}", file.display());
+    }
+    write(&workspace, &workspace_text)?;
+    write(&runner, &runner_text)?;
+    write(&solvers, &solvers_text)?;
+    println!("added rs_{day} to {}, {} and {}", workspace.display(), runner.display(), solvers.display());
+    println!("replace Solve::Unsolved of a part in {} once it is solved:", solvers.display());
+    for part in 1..=2 {
+        println!("{}", solver_line(day, part));
+    }
+    Ok(())
+}
+
+fn solver_line(day: u8, part: u8) -> String {
+    format!("    Solver {{ day: {day}, part: {part}, solve: Solve::Input(|input| solve(input, rs_{day}::parse, rs_{day}::part{part})) }},")
+}
+
+// copies the files of `template` which `day_dir` doesn't have yet, returns the created files
+fn copy_template(template: &Path, day_dir: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(template).map_err(|err| format!("failed to read '{}': {err}", template.display()))?;
+    let mut entries = entries.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("failed to read '{}': {err}", template.display()))?;
+    entries.sort();
+
+    std::fs::create_dir_all(day_dir).map_err(|err| format!("failed to create '{}': {err}", day_dir.display()))?;
+    let mut created = Vec::new();
+    for path in entries {
+        let target = day_dir.join(path.file_name().unwrap_or_default());
+        if path.is_dir() {
+            created.extend(copy_template(&path, &target, day)?);
+        } else if !target.exists() {
+            write(&target, &render(&read(&path)?, day))?;
+            created.push(target);
+        }
+    }
+    Ok(created)
+}
+
+fn render(template: &str, day: u8) -> String {
+    template.replace(PLACEHOLDER, &day.to_string())
+}
+
+// inserts `new` in front of the first line which belongs to a later day according to `key`,
+// or after the last line which belongs to an earlier one
+fn insert(text: &str, new: &[String], day: u8, key: fn(&str) -> Option<u8>) -> Result<String, String> {
+    let mut lines = text.lines().map(str::to_string).collect::<Vec<_>>();
+    let keyed = lines.iter().enumerate().filter_map(|(i, ln)| key(ln).map(|d| (i, d))).collect::<Vec<_>>();
+    if keyed.iter().any(|&(_, d)| d == day) {
+        return Err(format!("day {day} is listed already"));
+    }
+    let at = match keyed.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => keyed.last().map(|&(i, _)| i + 1).ok_or("no day is listed, don't know where to add it")?,
+    };
+    lines.splice(at..at, new.iter().cloned());
+    Ok(lines.join("\n") + "\n")
+}
+
+// "    \"08/rs_8-1\","
+fn member_day(ln: &str) -> Option<u8> {
+    ln.trim().strip_prefix('"')?.get(..2)?.parse().ok()
+}
+
+// "rs_21 = { path = \"../21/rs\" }"
+fn dependency_day(ln: &str) -> Option<u8> {
+    ln.strip_prefix("rs_")?.split(['-', ' ']).next()?.parse().ok()
+}
+
+// "    Solver { day: 21, part: 1, ..."
+fn solver_day(ln: &str) -> Option<u8> {
+    ln.strip_prefix("    Solver { day: ")?.split(',').next()?.parse().ok()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|err| format!("failed to read '{}': {err}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("failed to create '{}': {err}", dir.display()))?;
+    }
+    std::fs::write(path, text).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{dependency_day, insert, member_day, new_day, render, repository, solver_day, solver_line, TEMPLATE_DIR};
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(member_day("    \"08/rs_8-1\","), Some(8));
+        assert_eq!(member_day("    \"aoc\","), None);
+        assert_eq!(dependency_day("rs_8-2 = { path = \"../08/rs_8-2\" }"), Some(8));
+        assert_eq!(dependency_day("common = { path = \"../common\" }"), None);
+        assert_eq!(solver_day("    Solver { day: 19, part: 1, solve: Solve::Unsolved },"), Some(19));
+        assert_eq!(solver_line(21, 1), "    Solver { day: 21, part: 1, solve: Solve::Input(|input| solve(input, rs_21::parse, rs_21::part1)) },");
+    }
+
+    #[test]
+    fn test_insert() {
+        let text = "[workspace]\nmembers = [\n    \"aoc\",\n    \"19/rs\",\n    \"21/rs\",\n]\n";
+        let new = ["    \"20/rs\",".to_string()];
+        assert_eq!(insert(text, &new, 20, member_day), Ok(text.replace("    \"21/rs\"", "    \"20/rs\",\n    \"21/rs\"")));
+        let new = ["    \"24/rs\",".to_string()];
+        assert_eq!(insert(text, &new, 24, member_day), Ok(text.replace("    \"21/rs\",", "    \"21/rs\",\n    \"24/rs\",")));
+        assert!(insert(text, &new, 21, member_day).is_err());
+        assert!(insert("[workspace]\n", &new, 24, member_day).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render("name = \"rs_{{day}}\"", 20), "name = \"rs_20\"");
+    }
+
+    #[test]
+    fn test_new_day() {
+        let repo = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&repo);
+        std::fs::create_dir_all(repo.join("aoc/src")).unwrap();
+        std::fs::create_dir_all(repo.join("20")).unwrap();
+        std::fs::write(repo.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc\",\n    \"19/rs\",\n    \"21/rs\",\n]\n").unwrap();
+        std::fs::write(repo.join("aoc/Cargo.toml"), "[dependencies]\ncommon = { path = \"../common\" }\nrs_19 = { path = \"../19/rs\" }\n").unwrap();
+        std::fs::write(repo.join("aoc/src/solvers.rs"), "pub const SOLVERS: &[Solver] = &[\n    Solver { day: 19, part: 1, solve: Solve::Embedded(|| rs_19::part1().map(|result| result.to_string())) },\n];\n").unwrap();
+        let status = std::process::Command::new("cp").arg("-r").arg(repository().join(TEMPLATE_DIR)).arg(&repo).status().unwrap();
+        assert!(status.success());
+        std::fs::write(repo.join("20/examples"), "1 exp 32000000\n").unwrap();
+
+        let created = new_day(&repo, 20);
+        let again = new_day(&repo, 20);
+        let read = |file: &str| std::fs::read_to_string(repo.join(file)).unwrap();
+        let (workspace, runner, solvers, manifest, main) = (read("Cargo.toml"), read("aoc/Cargo.toml"), read("aoc/src/solvers.rs"), read("20/examples"), read("20/rs/src/main.rs"));
+        let lib = repo.join("20/rs/src/lib.rs").is_file();
+        std::fs::remove_dir_all(&repo).unwrap();
+
+        assert_eq!(created, Ok(()));
+        assert!(again.is_err());
+        assert!(workspace.contains("    \"19/rs\",\n    \"20/rs\",\n    \"21/rs\","));
+        assert!(runner.ends_with("rs_19 = { path = \"../19/rs\" }\nrs_20 = { path = \"../20/rs\" }\n"));
+        // the stubs of the template are no answers
+        assert!(solvers.ends_with("result.to_string())) },\n    Solver { day: 20, part: 1, solve: Solve::Unsolved },\n    Solver { day: 20, part: 2, solve: Solve::Unsolved },\n];\n"));
+        assert!(main.contains("parse_part_from_args(20, rs_20::parse)"));
+        assert!(lib);
+        // files of the day which exist already are kept
+        assert_eq!(manifest, "1 exp 32000000\n");
+    }
+}
@@ -0,0 +1,389 @@
+use std::path::Path;
+
+use common::crypt::{self, Key};
+use common::input;
+
+use crate::client::Client;
+use crate::extract::TASK_FILES;
+use crate::guesses::{self, Guess, Verdict};
+
+// Downloads the puzzles into the day directories and submits answers,
+// scraping what it needs from the HTML of the website.
+
+pub const YEAR: u16 = 2023;
+const PART_TWO: &str = "--- Part Two ---";
+
+fn day_path(day: u8) -> String {
+    format!("/{YEAR}/day/{day}")
+}
+
+// saves the puzzle input if there is none yet, encrypted if there is a key,
+// and the puzzle text if it is missing or lacks part two,
+// the puzzle text of the first days has been saved by hand and is kept otherwise
+pub fn download(client: &Client, day: u8, day_dir: &Path, key: Option<&Key>) -> Result<(), String> {
+    std::fs::create_dir_all(day_dir).map_err(|err| format!("failed to create '{}': {err}", day_dir.display()))?;
+    let input = day_dir.join(input::INPUT);
+    let encrypted = crypt::encrypted_path(&input);
+    if !input.exists() && !encrypted.exists() {
+        let text = client.fetch(&format!("{}/input", day_path(day)))?;
+        match key {
+            Some(key) => {
+                std::fs::write(&encrypted, crypt::encrypt(key, text.as_bytes()))
+                    .map_err(|err| format!("failed to write '{}': {err}", encrypted.display()))?;
+                println!("{day:02}: saved {}", encrypted.display());
+            }
+            None => {
+                write(&input, &text)?;
+                println!("{day:02}: saved {}", input.display());
+            }
+        }
+    }
+
+    let task = TASK_FILES.iter().map(|name| day_dir.join(name)).find(|path| path.is_file()).unwrap_or(day_dir.join("task"));
+    let saved = std::fs::read_to_string(&task).unwrap_or_default();
+    if saved.is_empty() || !saved.contains(PART_TWO) {
+        if !saved.is_empty() {
+            // the text has been saved from the cached page, part two may have been unlocked since
+            client.forget(&day_path(day));
+        }
+        let text = task_text(&client.get(&day_path(day))?)?;
+        if text.trim_end() != saved.trim_end() {
+            write(&task, &text)?;
+            println!("{day:02}: saved {}", task.display());
+        }
+    }
+    Ok(())
+}
+
+// submits the answer and logs the verdict as guess,
+// answers which can't be right according to the guesses logged before are only submitted with `force`
+pub fn submit(client: &Client, guess: &Guess, day_dir: &Path, force: bool) -> Result<Verdict, String> {
+    let logged = guesses::read(day_dir)?;
+    if logged.iter().any(|g| g.part == guess.part && g.verdict == Verdict::Correct) {
+        return Err(format!("{}-{} has been solved already", guess.day, guess.part));
+    }
+    let warnings = guesses::check(&logged, guess.part, &guess.value);
+    if !warnings.is_empty() && !force {
+        return Err(format!("not submitting, {}", warnings.join(", ")));
+    }
+
+    let path = day_path(guess.day);
+    let part = guess.part.to_string();
+    let cache_as = format!("{path}/answer-{}-{}", guess.part, guess.value);
+    let page = client.post(&format!("{path}/answer"), &[("level", &part), ("answer", &guess.value)], &cache_as)?;
+    let verdict = verdict(&page)?;
+    guesses::append(day_dir, &Guess { verdict, ..guess.clone() })?;
+    if verdict == Verdict::Correct {
+        // the puzzle text continues with part two now
+        client.forget(&path);
+    }
+    Ok(verdict)
+}
+
+// reads the verdict from the text of the page answering a submission
+fn verdict(page: &str) -> Result<Verdict, String> {
+    let text = articles(page).join(" ");
+    let lower = text.to_lowercase();
+    if lower.contains("that's the right answer") {
+        Ok(Verdict::Correct)
+    } else if lower.contains("too high") {
+        Ok(Verdict::TooHigh)
+    } else if lower.contains("too low") {
+        Ok(Verdict::TooLow)
+    } else if lower.contains("not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if text.is_empty() {
+        Err("unexpected answer page without text".to_string())
+    } else {
+        // like answering too recently or a part which is locked, the website explains itself
+        Err(text)
+    }
+}
+
+// the puzzle text like it is shown in the browser, with both parts if part two is unlocked
+fn task_text(page: &str) -> Result<String, String> {
+    let parts = articles(page);
+    if parts.is_empty() {
+        return Err("there is no puzzle text in the page".to_string());
+    }
+    Ok(parts.join("\n\n") + "\n")
+}
+
+// the text of the articles of the page, which hold the puzzle text or the answer to a submission
+fn articles(page: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let Some(open) = rest[start..].find('>') else {
+            break;
+        };
+        let content = &rest[start + open + 1..];
+        let end = content.find("</article>").unwrap_or(content.len());
+        out.push(to_text(&content[..end]));
+        rest = &content[end..];
+    }
+    out
+}
+
+// paragraphs are separated by empty lines, lists are indented and code blocks are kept as they are
+fn to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut pre = false;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, ""),
+        };
+        let text = decode(text);
+        if pre {
+            out += &text;
+        } else {
+            let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.starts_with(char::is_whitespace) && !out.ends_with([' ', '\n']) && !collapsed.is_empty() {
+                out.push(' ');
+            }
+            out += &collapsed;
+            if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                out.push(' ');
+            }
+        }
+        let Some(end) = tag.find('>') else {
+            break;
+        };
+        let name = tag[1..end].split_whitespace().next().unwrap_or_default().to_lowercase();
+        match name.as_str() {
+            "pre" => pre = true,
+            "/pre" => {
+                pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            "li" => out += "    ",
+            "/li" => out.push('\n'),
+            "/ul" | "/ol" => out.push('\n'),
+            "/h2" | "/p" => out += "\n\n",
+            _ => {}
+        }
+        rest = &tag[end + 1..];
+    }
+    out.lines().map(str::trim_end).collect::<Vec<_>>().join("\n").trim().to_string()
+}
+
+fn decode(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out += &rest[..i];
+        rest = &rest[i..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let decoded = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some(' '),
+            Some(code) if code.starts_with("#x") => u32::from_str_radix(&code[2..], 16).ok().and_then(char::from_u32),
+            Some(code) if code.starts_with('#') => code[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match (decoded, entity) {
+            (Some(c), Some(entity)) => {
+                out.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out + rest
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    std::fs::write(path, text).map_err(|err| format!("failed to write '{}': {err}", path.display()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use common::crypt::{self, Key};
+
+    use crate::client::Client;
+    use crate::guesses::{self, Guess, Verdict};
+    use crate::site::{decode, download, submit, task_text, verdict};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 7: Camel Cards ---</h2><p>Your all-expenses-paid trip turns out to be a <em>one-way</em> trip.</p>
+<p>For example:</p>
+<pre><code>32T3K 765
+T55X5 684
+</code></pre>
+<ul>
+<li><code>32T3K</code> is the only <em>one pair</em>.</li>
+<li>So, the first step is to put them in order &amp; rank them.</li>
+</ul>
+<p>Find the rank of every hand. What are the total winnings?</p>
+</article>
+<p>Your puzzle answer was <code>252295678</code>.</p>
+</main></body></html>
+"#;
+
+    const TEXT: &str = "\
+--- Day 7: Camel Cards ---
+
+Your all-expenses-paid trip turns out to be a one-way trip.
+
+For example:
+
+32T3K 765
+T55X5 684
+
+    32T3K is the only one pair.
+    So, the first step is to put them in order & rank them.
+
+Find the rank of every hand. What are the total winnings?
+";
+
+    struct Request {
+        at: Instant,
+        head: String,
+        body: String,
+    }
+
+    // answers every request with the body `respond` returns for its path, until the test ends
+    fn serve(respond: fn(&str) -> (u16, String)) -> (String, Arc<Mutex<Vec<Request>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut head = String::new();
+                while reader.read_line(&mut head).unwrap() > 2 {}
+                let length = head.lines().find_map(|ln| ln.to_lowercase().strip_prefix("content-length: ").map(|n| n.trim().parse().unwrap())).unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let path = head.split_whitespace().nth(1).unwrap().to_string();
+                log.lock().unwrap().push(Request { at: Instant::now(), head, body: String::from_utf8(body).unwrap() });
+                let (status, body) = respond(&path);
+                write!(stream, "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn website(path: &str) -> (u16, String) {
+        match path {
+            "/2023/day/7" => (200, PAGE.to_string()),
+            "/2023/day/7/input" => (200, "32T3K 765\n".to_string()),
+            "/2023/day/7/answer" => (200, "<main><article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article></main>".to_string()),
+            _ => (404, "Please don't repeatedly request this endpoint before it unlocks!".to_string()),
+        }
+    }
+
+    fn website_part_two(path: &str) -> (u16, String) {
+        match path {
+            "/2023/day/7" => (200, PAGE.replace("</article>", "</article><article><h2>--- Part Two ---</h2><p>Now J cards are jokers.</p></article>")),
+            _ => website(path),
+        }
+    }
+
+    fn temp_root(name: &str) -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_download_and_submit() {
+        let (url, requests) = serve(website);
+        let root = temp_root("site");
+        let client = Client::new(&url, "53cr3t", root.join(".cache"), Duration::from_millis(200));
+        let day_dir = root.join("07");
+
+        download(&client, 7, &day_dir, None).unwrap();
+        assert_eq!(std::fs::read_to_string(day_dir.join("input")).unwrap(), "32T3K 765\n");
+        assert_eq!(std::fs::read_to_string(day_dir.join("task")).unwrap(), TEXT);
+        // the page has been cached and the input is there already
+        std::fs::remove_file(day_dir.join("task")).unwrap();
+        download(&client, 7, &day_dir, None).unwrap();
+        assert_eq!(std::fs::read_to_string(day_dir.join("task")).unwrap(), TEXT);
+        let key = Key::generate();
+        download(&client, 7, &root.join("encrypted"), Some(&key)).unwrap();
+        assert!(!root.join("encrypted/input").exists());
+        assert_eq!(crypt::decrypt(&key, &std::fs::read(root.join("encrypted/input.enc")).unwrap()), Ok(b"32T3K 765\n".to_vec()));
+        // the input is fetched again rather than kept in the cache in plain text
+        assert!(!root.join(".cache/2023-day-7-input").exists());
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let guess = Guess { day: 7, part: 1, value: "252295679".to_string(), verdict: Verdict::Wrong };
+        assert_eq!(submit(&client, &guess, &day_dir, false), Ok(Verdict::TooHigh));
+        assert_eq!(guesses::read(&day_dir).unwrap(), vec![Guess { verdict: Verdict::TooHigh, ..guess.clone() }]);
+        // the website has said it is too high already
+        assert!(submit(&client, &guess, &day_dir, false).is_err());
+        assert!(download(&client, 8, &root.join("08"), None).unwrap_err().contains("failed with status 404"));
+
+        let requests = requests.lock().unwrap();
+        let heads = requests.iter().map(|r| r.head.lines().next().unwrap()).collect::<Vec<_>>();
+        assert_eq!(heads, vec!["GET /2023/day/7/input HTTP/1.1", "GET /2023/day/7 HTTP/1.1", "GET /2023/day/7/input HTTP/1.1", "POST /2023/day/7/answer HTTP/1.1", "GET /2023/day/8/input HTTP/1.1"]);
+        assert!(requests.iter().all(|r| r.head.contains("Cookie: session=53cr3t\r\n")));
+        assert_eq!(requests[3].body, "level=1&answer=252295679");
+        assert!(requests.windows(2).all(|w| w[1].at - w[0].at >= Duration::from_millis(150)));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_download_part_two() {
+        let (url, requests) = serve(website_part_two);
+        let root = temp_root("part-two");
+        let client = Client::new(&url, "53cr3t", root.join(".cache"), Duration::from_millis(10));
+        let day_dir = root.join("07");
+        std::fs::create_dir_all(&day_dir).unwrap();
+        std::fs::write(day_dir.join("input"), "32T3K 765\n").unwrap();
+        std::fs::write(day_dir.join("task"), TEXT).unwrap();
+        // the page from before part two has been unlocked
+        std::fs::create_dir_all(root.join(".cache")).unwrap();
+        std::fs::write(root.join(".cache/2023-day-7"), PAGE).unwrap();
+
+        download(&client, 7, &day_dir, None).unwrap();
+        assert_eq!(std::fs::read_to_string(day_dir.join("task")).unwrap(), format!("{TEXT}\n--- Part Two ---\n\nNow J cards are jokers.\n"));
+        download(&client, 7, &day_dir, None).unwrap();
+        let heads = requests.lock().unwrap().iter().map(|r| r.head.lines().next().unwrap().to_string()).collect::<Vec<_>>();
+        assert_eq!(heads, vec!["GET /2023/day/7 HTTP/1.1"]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        assert_eq!(verdict(&page("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.")), Ok(Verdict::Correct));
+        assert_eq!(verdict(&page("That's not the right answer; your answer is too low.")), Ok(Verdict::TooLow));
+        assert_eq!(verdict(&page("That's not the right answer. If you're stuck, ...")), Ok(Verdict::Wrong));
+        assert_eq!(verdict(&page("You gave an answer too recently; you have 41s left to wait.")), Err("You gave an answer too recently; you have 41s left to wait.".to_string()));
+    }
+
+    #[test]
+    fn test_task_text() {
+        assert_eq!(task_text(PAGE).as_deref(), Ok(TEXT));
+        assert!(task_text("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("a &lt;b&gt; &amp;&#39;c&#x21; & d;"), "a <b> &'c! & d;");
+    }
+}
@@ -0,0 +1,139 @@
+use std::borrow::Borrow;
+use std::time::{Duration, Instant};
+
+use common::input::Source;
+use common::parse::ParseError;
+use common::stats;
+
+pub enum Solve {
+    // parses the puzzle input given as text at runtime
+    Input(fn(&str) -> Result<Solution, ParseError>),
+    // the puzzle input has been compiled into the solver by a proc macro
+    Embedded(fn() -> Result<String, String>),
+    // added by `aoc new`, fails until it is replaced by the solver of the part
+    #[allow(dead_code)]
+    Unsolved,
+}
+
+pub struct Solution {
+    pub answer: String,
+    // None if the input has been parsed at compile time
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+    // whatever the solver has recorded with common::stats while solving
+    pub stats: Vec<(&'static str, u64)>,
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub solve: Solve,
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver { day: 1, part: 1, solve: Solve::Input(|input| solve(input, rs_1::parse, rs_1::part1)) },
+    Solver { day: 1, part: 2, solve: Solve::Input(|input| solve(input, rs_1::parse, rs_1::part2)) },
+    Solver { day: 2, part: 1, solve: Solve::Input(|input| solve(input, rs_2::parse, rs_2::part1)) },
+    Solver { day: 2, part: 2, solve: Solve::Input(|input| solve(input, rs_2::parse, rs_2::part2)) },
+    Solver { day: 3, part: 1, solve: Solve::Input(|input| solve(input, rs_3::parse, rs_3::part1)) },
+    Solver { day: 3, part: 2, solve: Solve::Input(|input| solve(input, rs_3::parse, rs_3::part2)) },
+    Solver { day: 4, part: 1, solve: Solve::Input(|input| solve(input, rs_4::parse, rs_4::part1)) },
+    Solver { day: 4, part: 2, solve: Solve::Input(|input| solve(input, rs_4::parse, rs_4::part2)) },
+    Solver { day: 5, part: 1, solve: Solve::Input(|input| solve(input, rs_5::parse, rs_5::part1)) },
+    Solver { day: 5, part: 2, solve: Solve::Input(|input| solve(input, rs_5::parse_ranges, rs_5::part2)) },
+    Solver { day: 6, part: 1, solve: Solve::Input(|input| solve(input, rs_6::parse, rs_6::part1)) },
+    Solver { day: 6, part: 2, solve: Solve::Input(|input| solve(input, rs_6::parse, rs_6::part2)) },
+    Solver { day: 7, part: 1, solve: Solve::Input(|input| solve(input, rs_7::parse, rs_7::part1)) },
+    Solver { day: 7, part: 2, solve: Solve::Input(|input| solve(input, rs_7::parse, rs_7::part2)) },
+    Solver { day: 8, part: 1, solve: Solve::Input(|input| solve(input, rs_8_1::parse, rs_8_1::part1)) },
+    Solver { day: 8, part: 2, solve: Solve::Embedded(|| rs_8_2::part2().map(|steps| steps.to_string())) },
+    Solver { day: 9, part: 1, solve: Solve::Input(|input| solve(input, rs_9::parse, rs_9::part1)) },
+    Solver { day: 9, part: 2, solve: Solve::Input(|input| solve(input, rs_9::parse, rs_9::part2)) },
+    Solver { day: 10, part: 1, solve: Solve::Input(|input| solve(input, rs_10::parse, rs_10::part1)) },
+    Solver { day: 10, part: 2, solve: Solve::Input(|input| solve(input, rs_10::parse, rs_10::part2)) },
+    Solver { day: 11, part: 1, solve: Solve::Input(|input| solve(input, rs_11::parse, rs_11::part1)) },
+    Solver { day: 11, part: 2, solve: Solve::Input(|input| solve(input, rs_11::parse, rs_11::part2)) },
+    Solver { day: 12, part: 1, solve: Solve::Input(|input| solve(input, rs_12::parse, rs_12::part1)) },
+    Solver { day: 13, part: 1, solve: Solve::Input(|input| solve(input, rs_13::parse, rs_13::part1)) },
+    Solver { day: 14, part: 1, solve: Solve::Input(|input| solve(input, rs_14::parse, rs_14::part1)) },
+    Solver { day: 15, part: 1, solve: Solve::Input(|input| solve(input, rs_15::parse, rs_15::part1)) },
+    Solver { day: 15, part: 2, solve: Solve::Input(|input| solve(input, rs_15::parse, rs_15::part2)) },
+    Solver { day: 16, part: 1, solve: Solve::Input(|input| solve(input, rs_16::parse, rs_16::part1)) },
+    Solver { day: 16, part: 2, solve: Solve::Input(|input| solve(input, rs_16::parse, rs_16::part2)) },
+    Solver { day: 17, part: 1, solve: Solve::Input(|input| solve(input, rs_17::parse, rs_17::part1)) },
+    Solver { day: 18, part: 1, solve: Solve::Input(|input| solve(input, rs_18::parse, rs_18::part1)) },
+    Solver { day: 19, part: 1, solve: Solve::Embedded(|| rs_19::part1().map(|result| result.to_string())) },
+    Solver { day: 21, part: 1, solve: Solve::Input(|input| solve(input, rs_21::parse, rs_21::part1)) },
+    Solver { day: 22, part: 1, solve: Solve::Input(|input| solve(input, rs_22::parse, rs_22::part1)) },
+    Solver { day: 23, part: 1, solve: Solve::Input(|input| solve(input, rs_23::parse, rs_23::part1)) },
+    Solver { day: 23, part: 2, solve: Solve::Input(|input| solve(input, rs_23::parse, rs_23::part2)) },
+];
+
+impl Solver {
+    pub fn run(&self, input: &Source) -> Result<String, String> {
+        self.time(input).map(|solution| solution.answer)
+    }
+
+    pub fn time(&self, input: &Source) -> Result<Solution, String> {
+        match self.solve {
+            Solve::Input(solve) => {
+                let text = input.read(self.day)?;
+                solve(&text).map_err(|err| err.locate(&text).in_file(&input.resolve(self.day)).to_string())
+            }
+            Solve::Embedded(solve) => match input {
+                Source::Input => {
+                    stats::take();
+                    let start = Instant::now();
+                    let answer = solve()?;
+                    Ok(Solution { answer, parse: None, solve: start.elapsed(), stats: stats::take() })
+                }
+                _ => Err(format!("day {} part {} has its input compiled in, --input and --example are not supported", self.day, self.part)),
+            },
+            Solve::Unsolved => Err(format!("day {} part {} is not solved yet, its solver in aoc/src/solvers.rs is a stub", self.day, self.part)),
+        }
+    }
+}
+
+// parse results are often vectors while the parts take slices, hence the Borrow
+fn solve<T: Borrow<U>, U: ?Sized, A: ToString>(input: &str, parse: fn(&str) -> Result<T, ParseError>, part: fn(&U) -> A) -> Result<Solution, ParseError> {
+    let start = Instant::now();
+    let parsed = parse(input)?;
+    let parse = start.elapsed();
+    stats::take();
+    let start = Instant::now();
+    let answer = part(parsed.borrow()).to_string();
+    Ok(Solution { answer, parse: Some(parse), solve: start.elapsed(), stats: stats::take() })
+}
+
+pub fn find(day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+// leaves out the stubs of days which have been generated but not solved, they have no answers to check
+pub fn find_solved(day: u8, part: u8) -> Option<&'static Solver> {
+    find(day, part).filter(|s| !matches!(s.solve, Solve::Unsolved))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use common::input::Source;
+
+    use crate::solvers::{find, Solve, Solver};
+
+    // parse errors of a malformed input point into the file it has been read from
+    #[test]
+    fn test_parse_error_diagnostic() {
+        let path = std::env::temp_dir().join(format!("aoc-malformed-{}", std::process::id()));
+        std::fs::write(&path, "32T3K 765\nT55X5 684\n").unwrap();
+        let err = find(7, 1).unwrap().run(&Source::Path(path.clone())).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err, format!("{}:2:4: unknown card 'X'\n2 | T55X5 684\n  |    ^", path.display()));
+    }
+
+    #[test]
+    fn test_unsolved() {
+        let solver = Solver { day: 20, part: 1, solve: Solve::Unsolved };
+        assert_eq!(solver.run(&Source::Input).unwrap_err(), "day 20 part 1 is not solved yet, its solver in aoc/src/solvers.rs is a stub");
+    }
+}
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use common::input::{self, Source};
+
+use crate::answers::{self, Answer};
+use crate::solvers;
+use crate::watchdog::{self, Outcome};
+
+enum Status {
+    Pass,
+    Fail,
+    NoSolver,
+    Error(String),
+    // the actual answer is the best candidate the solver has found in time
+    TimedOut(Duration),
+}
+
+struct Row {
+    answer: Answer,
+    actual: Option<String>,
+    status: Status,
+}
+
+// returns whether all answers which have a solver are reproduced correctly,
+// solvers which run out of time neither pass nor fail
+pub fn verify(days: &[u8], timeout: Option<Duration>) -> Result<bool, String> {
+    let mut rows = Vec::new();
+    for &day in days {
+        for answer in answers::read(&input::day_dir(day))? {
+            rows.push(check(answer, timeout));
+        }
+    }
+    print_table(&rows);
+    Ok(rows.iter().all(|row| matches!(row.status, Status::Pass | Status::NoSolver | Status::TimedOut(_))))
+}
+
+fn check(answer: Answer, timeout: Option<Duration>) -> Row {
+    if solvers::find_solved(answer.day, answer.part).is_none() {
+        return Row { answer, actual: None, status: Status::NoSolver };
+    }
+    match watchdog::run(answer.day, answer.part, &Source::Input, timeout, false) {
+        Outcome::Answer(actual) => {
+            let status = if actual == answer.value { Status::Pass } else { Status::Fail };
+            Row { answer, actual: Some(actual), status }
+        }
+        Outcome::Error(err) => Row { answer, actual: None, status: Status::Error(err) },
+        Outcome::TimedOut(candidate) => Row { answer, actual: candidate, status: Status::TimedOut(timeout.unwrap_or_default()) },
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    println!("{:<6} {:<15} {:<15} result", "part", "expected", "actual");
+    for row in rows {
+        let status = match &row.status {
+            Status::Pass => "ok".to_string(),
+            Status::Fail => "FAILED".to_string(),
+            Status::NoSolver => "no solver".to_string(),
+            // parse errors come with the offending line, which doesn't fit into the table
+            Status::Error(err) => err.lines().next().unwrap_or_default().to_string(),
+            Status::TimedOut(timeout) => format!("timed out after {timeout:?}"),
+        };
+        println!(
+            "{:<6} {:<15} {:<15} {}",
+            format!("{}-{}", row.answer.day, row.answer.part),
+            row.answer.value,
+            row.actual.as_deref().unwrap_or("-"),
+            status,
+        );
+    }
+
+    let count = |f: fn(&Status) -> bool| rows.iter().filter(|row| f(&row.status)).count();
+    println!(
+        "{} passed, {} failed, {} timed out, {} without solver",
+        count(|s| matches!(s, Status::Pass)),
+        count(|s| matches!(s, Status::Fail | Status::Error(_))),
+        count(|s| matches!(s, Status::TimedOut(_))),
+        count(|s| matches!(s, Status::NoSolver)),
+    );
+}
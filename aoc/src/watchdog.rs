@@ -0,0 +1,169 @@
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::input::Source;
+use common::{log, progress};
+
+// Threads can't be stopped from the outside, so solvers which have a time budget
+// run in a child process of this binary which is killed when it overruns.
+
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    // with the best candidate the solver has reported before it has been killed
+    TimedOut(Option<String>),
+}
+
+struct Output {
+    // None if the process has been killed
+    status: Option<ExitStatus>,
+    stdout: String,
+    stderr: String,
+}
+
+// solves the part with `aoc run` in a child process, with `echo` its stderr is passed on while it runs,
+// the child writes the same log messages as this process
+pub fn run(day: u8, part: u8, input: &Source, timeout: Option<Duration>, echo: bool) -> Outcome {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => return Outcome::Error(format!("failed to find the aoc binary: {err}")),
+    };
+    let mut command = Command::new(exe);
+    command
+        .args(["run".to_string(), day.to_string(), part.to_string()])
+        .args(source_args(input))
+        .args(log::verbosity().to_args())
+        .env(progress::VAR, "1")
+        .stdin(if *input == Source::Stdin { Stdio::inherit() } else { Stdio::null() });
+    let output = match watch(command, timeout, echo) {
+        Ok(output) => output,
+        Err(err) => return Outcome::Error(err),
+    };
+
+    let Some(status) = output.status else {
+        return Outcome::TimedOut(progress::last_candidate(&output.stderr).map(str::to_string));
+    };
+    let prefix = format!("{day}-{part}: ");
+    match output.stdout.lines().find_map(|ln| ln.strip_prefix(&prefix)) {
+        Some(answer) if status.success() => Outcome::Answer(answer.to_string()),
+        _ if output.stderr.contains("panicked") => match panic_message(&output.stderr) {
+            Some(message) => Outcome::Error(format!("panicked: {message}")),
+            None => Outcome::Error("panicked".to_string()),
+        },
+        _ => {
+            let errors = output.stderr.lines().filter(|ln| !progress::is_candidate(ln)).collect::<Vec<_>>();
+            Outcome::Error(if errors.is_empty() { format!("failed with {status}") } else { errors.join("\n") })
+        }
+    }
+}
+
+// the message follows the line with the location of the panic, older versions of Rust put both on one line
+fn panic_message(stderr: &str) -> Option<&str> {
+    let mut lines = stderr.lines().skip_while(|ln| !ln.contains(" panicked at "));
+    let location = lines.next()?;
+    Some(lines.next().filter(|_| location.ends_with(':')).unwrap_or(location))
+}
+
+fn source_args(input: &Source) -> Vec<String> {
+    match input {
+        Source::Input => vec![],
+        Source::Example(name) => vec!["--example".to_string(), name.clone()],
+        Source::Path(path) => vec!["--input".to_string(), path.display().to_string()],
+        Source::Stdin => vec!["--input".to_string(), "-".to_string()],
+    }
+}
+
+// runs `command` and kills it if it takes longer than `timeout`
+fn watch(mut command: Command, timeout: Option<Duration>, echo: bool) -> Result<Output, String> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to start {command:?}: {err}"))?;
+
+    // the pipes are read while waiting, otherwise a child writing a lot would block on them
+    let mut stdout = child.stdout.take().unwrap();
+    let stdout = thread::spawn(move || {
+        let mut out = String::new();
+        let _ = stdout.read_to_string(&mut out);
+        out
+    });
+    let mut stderr = child.stderr.take().unwrap();
+    let stderr = thread::spawn(move || {
+        let mut out = Vec::new();
+        let mut buf = [0; 4096];
+        while let Ok(n @ 1..) = stderr.read(&mut buf) {
+            if echo {
+                eprint!("{}", String::from_utf8_lossy(&buf[..n]));
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        String::from_utf8_lossy(&out).to_string()
+    });
+
+    let status = wait(&mut child, timeout).map_err(|err| format!("failed to wait for {command:?}: {err}"))?;
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn wait(child: &mut Child, timeout: Option<Duration>) -> std::io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    use crate::watchdog::{panic_message, watch};
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_watch_finished() {
+        let output = watch(shell("echo 7-1: 42; echo oops >&2"), Some(Duration::from_secs(10)), false).unwrap();
+        assert!(output.status.is_some_and(|s| s.success()));
+        assert_eq!((output.stdout.as_str(), output.stderr.as_str()), ("7-1: 42\n", "oops\n"));
+    }
+
+    #[test]
+    fn test_watch_timed_out() {
+        let start = Instant::now();
+        let output = watch(shell("echo 'candidate 17: found new nearer location' >&2; exec sleep 10"), Some(Duration::from_millis(200)), false).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(output.status, None);
+        assert_eq!(common::progress::last_candidate(&output.stderr), Some("17"));
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let stderr = "candidate 17: found new nearer location\nthread 'main' panicked at 10/rs/src/lib.rs:155:29:\npipe leads outside of the map\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+        assert_eq!(panic_message(stderr), Some("pipe leads outside of the map"));
+        assert_eq!(panic_message("thread 'main' panicked at 'no start', src/lib.rs:3:5\n"), Some("thread 'main' panicked at 'no start', src/lib.rs:3:5"));
+        assert_eq!(panic_message("oops\n"), None);
+    }
+}
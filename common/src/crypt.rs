@@ -0,0 +1,181 @@
+// Puzzle inputs are personal and shouldn't be published, so they can be stored encrypted
+// next to where the plain file would be, as <name>.enc, with a key which stays on the machine.
+// Reading an input through this module decrypts it in memory, the plain text never touches the disk.
+// The key is 32 bytes in hex, taken from the environment variable or the key file in the puzzle root.
+// An encrypted file is the magic line followed by the nonce and the ChaCha20-Poly1305 ciphertext,
+// which also fails to decrypt if the file has been tampered with.
+
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+use crate::input;
+
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+pub const KEY_FILE: &str = ".input-key";
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"aoc encrypted input v1\n";
+const NONCE_LEN: usize = 12;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        let invalid = || "invalid key, expected 64 hex digits".to_string();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+// the key isn't printed by accident
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+pub fn key_path() -> PathBuf {
+    input::root().join(KEY_FILE)
+}
+
+// None if there is neither the environment variable nor the key file
+pub fn load_key() -> Result<Option<Key>, String> {
+    if let Ok(hex) = std::env::var(KEY_VAR) {
+        return Key::from_hex(&hex).map(Some).map_err(|err| format!("{KEY_VAR}: {err}"));
+    }
+    let path = key_path();
+    match std::fs::read_to_string(&path) {
+        Ok(hex) => Key::from_hex(&hex).map(Some).map_err(|err| format!("{}: {err}", path.display())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("failed to read '{}': {err}", path.display())),
+    }
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{EXTENSION}"));
+    path.with_file_name(name)
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+pub fn encrypt(key: &Key, plain: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key.cipher().encrypt(&nonce, plain).expect("encryption of a buffer in memory can't fail");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, String> {
+    let data = data.strip_prefix(MAGIC).ok_or("not an encrypted input")?;
+    if data.len() < NONCE_LEN {
+        return Err("encrypted input is truncated".to_string());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "failed to decrypt, the key doesn't match or the file is damaged".to_string())
+}
+
+// reads the file at `path`, or decrypts <path>.enc if there is only that
+pub fn read(path: &Path) -> Result<String, String> {
+    read_with(path, load_key)
+}
+
+fn read_with(path: &Path, load_key: impl FnOnce() -> Result<Option<Key>, String>) -> Result<String, String> {
+    let encrypted = encrypted_path(path);
+    if path.exists() || !encrypted.is_file() {
+        return std::fs::read_to_string(path).map_err(|err| format!("failed to read '{}': {err}", path.display()));
+    }
+    let data = std::fs::read(&encrypted).map_err(|err| format!("failed to read '{}': {err}", encrypted.display()))?;
+    let key = load_key()?.ok_or_else(|| {
+        format!("'{}' is encrypted, set {KEY_VAR} or save the key in '{}'", encrypted.display(), key_path().display())
+    })?;
+    let plain = decrypt(&key, &data).map_err(|err| format!("{}: {err}", encrypted.display()))?;
+    String::from_utf8(plain).map_err(|_| format!("{}: decrypted input is not UTF-8", encrypted.display()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::crypt::{decrypt, encrypt, encrypted_path, is_encrypted, read_with, Key};
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate();
+        let data = encrypt(&key, b"32T3K 765\n");
+        assert!(is_encrypted(&data));
+        assert_ne!(encrypt(&key, b"32T3K 765\n"), data);
+        assert_eq!(decrypt(&key, &data), Ok(b"32T3K 765\n".to_vec()));
+    }
+
+    #[test]
+    fn test_decrypt_invalid() {
+        let key = Key::generate();
+        let mut data = encrypt(&key, b"32T3K 765\n");
+        assert!(decrypt(&Key::generate(), &data).is_err());
+        assert!(decrypt(&key, b"32T3K 765\n").is_err());
+        assert!(decrypt(&key, &data[..30]).is_err());
+        *data.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&key, &data).is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&format!("{}\n", key.to_hex())), Ok(key));
+        assert!(Key::from_hex("00ff").is_err());
+        assert!(Key::from_hex(&"g".repeat(64)).is_err());
+        assert_eq!(format!("{:?}", Key::generate()), "Key(..)");
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(encrypted_path(Path::new("07/input")), Path::new("07/input.enc"));
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-crypt-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let key = Key::generate();
+        std::fs::write(dir.join("input.enc"), encrypt(&key, b"32T3K 765\n")).unwrap();
+        std::fs::write(dir.join("plain"), "T55J5 684\n").unwrap();
+
+        let decrypted = read_with(&dir.join("input"), || Ok(Some(key.clone())));
+        let without_key = read_with(&dir.join("input"), || Ok(None));
+        let plain = read_with(&dir.join("plain"), || panic!("the key is only needed for encrypted inputs"));
+        let missing = read_with(&dir.join("missing"), || Ok(None));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(decrypted, Ok("32T3K 765\n".to_string()));
+        assert!(without_key.unwrap_err().contains("is encrypted"));
+        assert_eq!(plain, Ok("T55J5 684\n".to_string()));
+        assert!(missing.is_err());
+    }
+}
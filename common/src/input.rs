@@ -0,0 +1,261 @@
+// Every day looks for its puzzle files in the same way:
+//   1. a path given explicitly on the command line with --input, or stdin with --input -
+//   2. <$AOC_PUZZLE_ROOT>/<day>/<name> if the environment variable is set
+//   3. <day>/<name> in this repository
+// where <name> is "input" unless an example is selected with --example.
+// Days which solve both parts in one binary take the part as first argument.
+// The verbosity options of the log module are accepted as well.
+// Inputs stored encrypted as <name>.enc are decrypted in memory, see the crypt module.
+
+use std::path::{Path, PathBuf};
+
+use crate::crypt;
+use crate::log::{self, Verbosity};
+use crate::parse::ParseError;
+
+pub const ROOT_VAR: &str = "AOC_PUZZLE_ROOT";
+pub const INPUT: &str = "input";
+// stdin has no path, this is only the name it has in messages
+pub const STDIN: &str = "<stdin>";
+
+pub const USAGE: &str = "[--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
+pub const PART_USAGE: &str = "<part> [--input PATH | --example NAME] [-v | -vv] [--trace CATEGORY,...]";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    #[default]
+    Input,
+    Example(String),
+    Path(PathBuf),
+    Stdin,
+}
+
+pub fn root() -> PathBuf {
+    match std::env::var_os(ROOT_VAR) {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf(),
+    }
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    root().join(format!("{day:02}"))
+}
+
+pub fn resolve(day: u8, name: &str) -> PathBuf {
+    day_dir(day).join(name)
+}
+
+// the input of a day binary selected by its command line arguments,
+// exits with a usage message if they are invalid
+pub fn from_args() -> Source {
+    let (_, source, verbosity) = parse_args(std::env::args().skip(1), false, &mut no_options).unwrap_or_else(|err| exit_usage(&err, USAGE));
+    log::enable(&verbosity);
+    source
+}
+
+// parses the input of a day binary selected by its command line arguments,
+// exits with a diagnostic pointing into the input if it is malformed
+pub fn parse_from_args<T>(day: u8, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    parse_source(day, &from_args(), parse)
+}
+
+// like parse_from_args for binaries with options of their own, see parse_part_from_args_with
+pub fn parse_from_args_with<T>(day: u8, usage: &str, mut option: impl FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    let (_, source, verbosity) = parse_args(std::env::args().skip(1), false, &mut option).unwrap_or_else(|err| exit_usage(&err, usage));
+    log::enable(&verbosity);
+    parse_source(day, &source, parse)
+}
+
+// like parse_from_args for the days which solve both parts in one binary
+pub fn parse_part_from_args<T>(day: u8, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> (u8, T) {
+    parse_part_from_args_with(day, PART_USAGE, no_options, parse)
+}
+
+// like parse_part_from_args for days with options of their own,
+// `option` gets every argument this module doesn't know and consumes its value like Source::parse_arg
+pub fn parse_part_from_args_with<T>(day: u8, usage: &str, mut option: impl FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> (u8, T) {
+    let (part, source, verbosity) = parse_args(std::env::args().skip(1), true, &mut option).unwrap_or_else(|err| exit_usage(&err, usage));
+    log::enable(&verbosity);
+    (part.unwrap(), parse_source(day, &source, parse))
+}
+
+// like parse_part_from_args for days which parse the input differently for each part
+pub fn parse_part_from_args_by<T>(day: u8, parse: impl FnOnce(u8, &str) -> Result<T, ParseError>) -> (u8, T) {
+    let (part, source, verbosity) = parse_args(std::env::args().skip(1), true, &mut no_options).unwrap_or_else(|err| exit_usage(&err, PART_USAGE));
+    log::enable(&verbosity);
+    let part = part.unwrap();
+    (part, parse_source(day, &source, |input| parse(part, input)))
+}
+
+fn no_options(_: &str, _: &mut dyn Iterator<Item = String>) -> Result<bool, String> {
+    Ok(false)
+}
+
+fn parse_args<F>(args: impl IntoIterator<Item = String>, with_part: bool, option: &mut F) -> Result<(Option<u8>, Source, Verbosity), String>
+where
+    F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+{
+    let mut args = args.into_iter();
+    let part = match with_part {
+        true => Some(parse_part(&args.next().ok_or("missing part")?)?),
+        false => None,
+    };
+    let mut source = Source::Input;
+    let mut verbosity = Verbosity::default();
+    while let Some(arg) = args.next() {
+        if !source.parse_arg(&arg, &mut args)? && !verbosity.parse_arg(&arg, &mut args)? && !option(&arg, &mut args)? {
+            return Err(format!("unexpected argument '{arg}'"));
+        }
+    }
+    Ok((part, source, verbosity))
+}
+
+pub fn parse_part(part: &str) -> Result<u8, String> {
+    match part.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("invalid part '{part}', expected 1 or 2")),
+    }
+}
+
+fn exit_usage(err: &str, usage: &str) -> ! {
+    eprintln!("{err}");
+    eprintln!("usage: {} {usage}", std::env::args().next().unwrap_or_default());
+    std::process::exit(2);
+}
+
+fn parse_source<T>(day: u8, source: &Source, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    let input = source.read(day).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(1);
+    });
+    parse(&input).unwrap_or_else(|err| {
+        eprintln!("{}", err.locate(&input).in_file(&source.resolve(day)));
+        std::process::exit(1);
+    })
+}
+
+// for tests, panics if the example does not exist
+pub fn read_example(day: u8, name: &str) -> String {
+    Source::Example(name.to_string()).read(day).unwrap()
+}
+
+impl Source {
+    // consumes the value of `arg` from `args` if `arg` is --input or --example,
+    // returns Ok(false) if `arg` is something else
+    pub fn parse_arg(&mut self, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<bool, String> {
+        let source = match arg {
+            "--input" => match args.next().ok_or("missing value for --input")?.as_str() {
+                "-" => Self::Stdin,
+                path => Self::Path(path.into()),
+            },
+            "--example" => Self::Example(args.next().ok_or("missing value for --example")?),
+            _ => return Ok(false),
+        };
+        if *self != Self::Input {
+            return Err("--input and --example can only be given once".to_string());
+        }
+        *self = source;
+        Ok(true)
+    }
+
+    pub fn resolve(&self, day: u8) -> PathBuf {
+        match self {
+            Self::Input => resolve(day, INPUT),
+            Self::Example(name) => resolve(day, name),
+            Self::Path(path) => path.clone(),
+            Self::Stdin => PathBuf::from(STDIN),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        if *self == Self::Stdin {
+            return std::io::read_to_string(std::io::stdin()).map_err(|err| format!("failed to read stdin: {err}"));
+        }
+        crypt::read(&self.resolve(day))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::input::{no_options, parse_args, Source};
+
+    fn parse(args: &[&str]) -> Result<Source, String> {
+        parse_args(args.iter().map(|a| a.to_string()), false, &mut no_options).map(|(_, source, _)| source)
+    }
+
+    #[test]
+    fn test_parse_default() {
+        assert_eq!(parse(&[]), Ok(Source::Input));
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse(&["--input", "/tmp/input"]), Ok(Source::Path(PathBuf::from("/tmp/input"))));
+    }
+
+    #[test]
+    fn test_parse_example() {
+        assert_eq!(parse(&["--example", "exp2_gap"]), Ok(Source::Example("exp2_gap".to_string())));
+    }
+
+    #[test]
+    fn test_parse_stdin() {
+        assert_eq!(parse(&["--input", "-"]), Ok(Source::Stdin));
+        assert_eq!(Source::Stdin.resolve(8), PathBuf::from("<stdin>"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--example", "exp", "--input", "x"]).is_err());
+        assert!(parse(&["--foo"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        let args = |args: &[&str]| parse_args(args.iter().map(|a| a.to_string()), true, &mut no_options).map(|(part, source, _)| (part, source));
+        assert_eq!(args(&["2", "--example", "exp"]), Ok((Some(2), Source::Example("exp".to_string()))));
+        assert!(args(&[]).is_err());
+        assert!(args(&["3"]).is_err());
+        assert!(args(&["--input", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        let args = ["-vv", "--example", "exp", "--trace", "steps"].map(str::to_string);
+        let (_, source, verbosity) = parse_args(args, false, &mut no_options).unwrap();
+        assert_eq!(source, Source::Example("exp".to_string()));
+        assert_eq!(verbosity.to_args(), vec!["-vv", "--trace", "steps"]);
+    }
+
+    #[test]
+    fn test_parse_own_option() {
+        let mut words = None;
+        let mut option = |arg: &str, args: &mut dyn Iterator<Item = String>| match arg {
+            "--words" => {
+                words = Some(args.next().ok_or("missing value for --words")?);
+                Ok(true)
+            }
+            _ => Ok(false),
+        };
+        let args = ["1", "--words", "de", "--example", "exp"].map(str::to_string);
+        let (part, source, _) = parse_args(args, true, &mut option).unwrap();
+        assert!(parse_args(["1", "--words"].map(str::to_string), true, &mut option).is_err());
+        assert!(parse_args(["1", "--foo"].map(str::to_string), true, &mut option).is_err());
+        assert_eq!((part, source, words.as_deref()), (Some(1), Source::Example("exp".to_string()), Some("de")));
+    }
+
+    #[test]
+    fn test_resolve_relative_to_day_directory() {
+        let path = Source::Example("exp_6_steps".to_string()).resolve(8);
+        assert!(path.ends_with("08/exp_6_steps"));
+        assert!(path.is_file());
+    }
+
+    #[test]
+    fn test_resolve_explicit_path() {
+        assert_eq!(Source::Path(PathBuf::from("foo/bar")).resolve(8), PathBuf::from("foo/bar"));
+    }
+}
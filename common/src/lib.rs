@@ -0,0 +1,6 @@
+pub mod crypt;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod progress;
+pub mod stats;
@@ -0,0 +1,151 @@
+// Diagnostics of the solvers, which are only written if they have been switched on at runtime:
+//   -v                     debug messages, like the state of a map after each phase
+//   -vv                    trace messages as well, like every step of a search, which can be a lot
+//   --trace CATEGORY,...   only messages of these categories, -vv if no level is given
+// The categories are chosen by each day, the messages are written to stderr as
+//   [galaxies] read galaxies:
+// Use the macros, they only format the message if it is going to be written.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+pub const USAGE: &str = "[-v | -vv] [--trace CATEGORY,...]";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Verbosity {
+    // 0 writes nothing, otherwise the highest Level written
+    pub level: u8,
+    // all categories if empty
+    pub categories: Vec<String>,
+}
+
+// the level is checked first and on its own, so disabled messages in hot loops cost a single load
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+static CATEGORIES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+#[macro_export]
+macro_rules! debug {
+    ($category:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Debug, $category) {
+            $crate::log::write($category, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($category:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Trace, $category) {
+            $crate::log::write($category, format_args!($($arg)+));
+        }
+    };
+}
+
+pub fn enable(verbosity: &Verbosity) {
+    *CATEGORIES.write().unwrap() = verbosity.categories.clone();
+    LEVEL.store(verbosity.level(), Ordering::Relaxed);
+}
+
+// the verbosity enabled in this process, to pass it on to child processes
+pub fn verbosity() -> Verbosity {
+    Verbosity { level: LEVEL.load(Ordering::Relaxed), categories: CATEGORIES.read().unwrap().clone() }
+}
+
+pub fn enabled(level: Level, category: &str) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed) && selects(&CATEGORIES.read().unwrap(), category)
+}
+
+pub fn write(category: &str, message: fmt::Arguments) {
+    eprintln!("[{category}] {message}");
+}
+
+fn selects(categories: &[String], category: &str) -> bool {
+    categories.is_empty() || categories.iter().any(|c| c == category)
+}
+
+impl Verbosity {
+    // consumes the value of `arg` from `args` if it is one of the options in USAGE,
+    // returns Ok(false) if `arg` is something else
+    pub fn parse_arg(&mut self, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<bool, String> {
+        match arg {
+            "-v" => self.level = (self.level + 1).min(Level::Trace as u8),
+            "-vv" => self.level = Level::Trace as u8,
+            "--trace" => {
+                let categories = args.next().ok_or("missing value for --trace")?;
+                self.categories.extend(categories.split(',').filter(|c| !c.is_empty()).map(str::to_string));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn level(&self) -> u8 {
+        match self.level {
+            0 if !self.categories.is_empty() => Level::Trace as u8,
+            level => level,
+        }
+    }
+
+    // the arguments parse_arg turns into this verbosity
+    pub fn to_args(&self) -> Vec<String> {
+        let mut out = match self.level {
+            0 => vec![],
+            1 => vec!["-v".to_string()],
+            _ => vec!["-vv".to_string()],
+        };
+        if !self.categories.is_empty() {
+            out.extend(["--trace".to_string(), self.categories.join(",")]);
+        }
+        out
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::log::{selects, Level, Verbosity};
+
+    fn parse(args: &[&str]) -> Result<Verbosity, String> {
+        let mut verbosity = Verbosity::default();
+        let mut args = args.iter().map(|a| a.to_string());
+        while let Some(arg) = args.next() {
+            if !verbosity.parse_arg(&arg, &mut args)? {
+                return Err(format!("unexpected argument '{arg}'"));
+            }
+        }
+        Ok(verbosity)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(&[]).map(|v| v.level()), Ok(0));
+        assert_eq!(parse(&["-v"]).map(|v| v.level()), Ok(Level::Debug as u8));
+        assert_eq!(parse(&["-v", "-v", "-v"]).map(|v| v.level()), Ok(Level::Trace as u8));
+        assert_eq!(parse(&["-vv"]).map(|v| v.level()), Ok(Level::Trace as u8));
+        let verbosity = parse(&["--trace", "steps,ways", "-v"]).unwrap();
+        assert_eq!((verbosity.level(), verbosity.categories), (Level::Debug as u8, vec!["steps".to_string(), "ways".to_string()]));
+        assert_eq!(parse(&["--trace", "steps"]).map(|v| v.level()), Ok(Level::Trace as u8));
+        assert!(parse(&["--trace"]).is_err());
+    }
+
+    #[test]
+    fn test_to_args() {
+        for args in [&[][..], &["-v"], &["-vv", "--trace", "steps,ways"]] {
+            assert_eq!(parse(args).unwrap().to_args(), args);
+        }
+    }
+
+    #[test]
+    fn test_selects() {
+        assert!(selects(&[], "steps"));
+        assert!(selects(&["ways".to_string(), "steps".to_string()], "steps"));
+        assert!(!selects(&["ways".to_string()], "steps"));
+    }
+}
@@ -0,0 +1,161 @@
+// Errors of the puzzle input parsers.
+// A parser only reports the token it choked on, which has to be a slice of the text it has been given.
+// Where that token is in the whole input is resolved afterwards with `locate`,
+// so the parsers of single lines don't need to know which line they are looking at.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub token: String,
+    pub location: Option<Location>,
+    pub file: Option<PathBuf>,
+    // the address of the token, only used to find it in the input
+    address: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    // both counted from 1 like editors do
+    pub line: usize,
+    pub column: usize,
+    // the whole line containing the token
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(token: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            token: token.to_string(),
+            location: None,
+            file: None,
+            address: token.as_ptr() as usize,
+        }
+    }
+
+    // for text which ends too early, points right behind it
+    pub fn missing(text: &str, message: impl Into<String>) -> Self {
+        Self::new(&text[text.len()..], message)
+    }
+
+    // finds the line and column of the token, if it is a slice of `input`
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.location.is_some() || !(start..=start + input.len()).contains(&self.address) {
+            return self;
+        }
+        let offset = self.address - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+        });
+        self
+    }
+
+    pub fn in_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+}
+
+// writes the error like a compiler, with the offending line and the token marked below it
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_ref().map_or("<input>".to_string(), |file| file.display().to_string());
+        match &self.location {
+            Some(location) => write!(f, "{file}:{}:{}: {}", location.line, location.column, self.message)?,
+            None => write!(f, "{file}: {}", self.message)?,
+        }
+        if !self.token.is_empty() {
+            write!(f, " '{}'", self.token)?;
+        }
+        if let Some(location) = &self.location {
+            let number = location.line.to_string();
+            write!(f, "\n{number} | {}", location.text)?;
+            let marker = "^".repeat(self.token.chars().count().max(1));
+            write!(f, "\n{} | {}{marker}", " ".repeat(number.len()), " ".repeat(location.column - 1))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::new(token, "invalid number"))
+}
+
+// the characters of `text` together with the slices holding them, to use them as tokens
+pub fn chars(text: &str) -> impl Iterator<Item = (char, &str)> {
+    text.char_indices().map(|(i, c)| (c, &text[i..i + c.len_utf8()]))
+}
+
+// like str::split_once, the error points behind `text` if the separator is missing
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator).ok_or_else(|| ParseError::missing(text, format!("missing '{separator}'")))
+}
+
+// like str::strip_prefix, the error points at as many characters as the prefix has
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let end = text.char_indices().nth(prefix.chars().count()).map_or(text.len(), |(i, _)| i);
+        ParseError::new(&text[..end], format!("expected '{prefix}' but got"))
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::parse::{chars, number, split_once, strip_prefix, ParseError};
+
+    const INPUT: &str = "32T3K 765\nT55X5 684\n";
+
+    #[test]
+    fn test_locate() {
+        let token = &INPUT[13..14];
+        let err = ParseError::new(token, "unknown card").locate(INPUT);
+        let location = err.location.unwrap();
+        assert_eq!((location.line, location.column, location.text.as_str()), (2, 4, "T55X5 684"));
+    }
+
+    #[test]
+    fn test_locate_missing() {
+        let ln = INPUT.lines().nth(1).unwrap();
+        let err = ParseError::missing(ln, "missing bid").locate(INPUT);
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 10)));
+    }
+
+    #[test]
+    fn test_locate_other_text() {
+        let err = ParseError::new("X", "unknown card").locate(INPUT);
+        assert_eq!(err.location, None);
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(&INPUT[13..14], "unknown card").locate(INPUT).in_file(Path::new("07/input"));
+        assert_eq!(err.to_string(), "07/input:2:4: unknown card 'X'\n2 | T55X5 684\n  |    ^");
+        assert_eq!(ParseError::new("X", "unknown card").to_string(), "<input>: unknown card 'X'");
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(chars("aß").collect::<Vec<_>>(), vec![('a', "a"), ('ß', "ß")]);
+        assert_eq!(number::<u8>("42"), Ok(42));
+        let err = number::<u8>(&INPUT[6..9]).unwrap_err().locate(INPUT);
+        assert_eq!((err.token.as_str(), err.location.unwrap().column), ("765", 7));
+        assert_eq!(split_once("Game 1: 3 blue", ": "), Ok(("Game 1", "3 blue")));
+        assert_eq!(split_once("Game 1", ": ").unwrap_err().message, "missing ': '");
+        assert_eq!(strip_prefix("Game 1", "Game "), Ok("1"));
+        assert_eq!(strip_prefix("Card 1", "Game ").unwrap_err().token, "Card ");
+    }
+}
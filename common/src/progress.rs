@@ -0,0 +1,49 @@
+// Solvers which search for a long time report every better candidate they find,
+// so a run which has to be stopped still has something to show.
+// The candidates are written to stderr as lines like
+//   candidate 17729182: found new nearer location
+// which the runner picks up from the output of solvers it had to kill.
+// Only the runner reads them, so they are only written if it has set the environment variable.
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+pub const VAR: &str = "AOC_PROGRESS";
+
+const PREFIX: &str = "candidate ";
+
+pub fn candidate(value: impl Display, message: &str) {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    if *ENABLED.get_or_init(|| std::env::var_os(VAR).is_some()) {
+        eprintln!("{PREFIX}{value}: {message}");
+    }
+}
+
+pub fn is_candidate(ln: &str) -> bool {
+    parse_candidate(ln).is_some()
+}
+
+// the value of the last candidate reported in `output`
+pub fn last_candidate(output: &str) -> Option<&str> {
+    output.lines().rev().find_map(parse_candidate)
+}
+
+fn parse_candidate(ln: &str) -> Option<&str> {
+    let (value, _) = ln.strip_prefix(PREFIX)?.split_once(": ")?;
+    Some(value)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::progress::{is_candidate, last_candidate};
+
+    #[test]
+    fn test_last_candidate() {
+        let output = "candidate 42: found new nearer location\nsomething else\ncandidate 17: found new nearer location\n";
+        assert_eq!(last_candidate(output), Some("17"));
+        assert_eq!(last_candidate("thread 'main' panicked\n"), None);
+        assert!(is_candidate("candidate 6426: found a longer hike"));
+        assert!(!is_candidate("candidates are ignored"));
+    }
+}
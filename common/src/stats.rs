@@ -0,0 +1,37 @@
+// Solvers can report numbers about their work next to the answer, like how many states they explored.
+// They are collected per thread, the runner takes them after each part and puts them into its JSON output.
+// Solvers should count in local variables and record the totals once, this isn't meant for hot loops.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static STATS: RefCell<Vec<(&'static str, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
+// adds `value` to the stat `name`, which is created if it hasn't been recorded yet
+pub fn record(name: &'static str, value: u64) {
+    STATS.with_borrow_mut(|stats| match stats.iter_mut().find(|(n, _)| *n == name) {
+        Some((_, total)) => *total += value,
+        None => stats.push((name, value)),
+    });
+}
+
+// the stats recorded on this thread since the last call, in the order they have been recorded first
+pub fn take() -> Vec<(&'static str, u64)> {
+    STATS.with_borrow_mut(std::mem::take)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::{record, take};
+
+    #[test]
+    fn test_record_and_take() {
+        record("hikes", 2);
+        record("dead_ends", 5);
+        record("hikes", 1);
+        assert_eq!(take(), vec![("hikes", 3), ("dead_ends", 5)]);
+        assert_eq!(take(), vec![]);
+    }
+}
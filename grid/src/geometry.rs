@@ -0,0 +1,201 @@
+// Coordinates and directions on a map where north is up, i.e. towards row 0.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign};
+
+// the integer types which can be used as coordinates
+pub trait Coordinate: Copy + Eq + Ord + Hash + Debug + Default {
+    const ONE: Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ONE: Self = 1;
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    }
+}
+
+coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Cor<T = usize> {
+    pub row: T,
+    pub col: T,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    N, S, W, E,
+}
+
+// a direction relative to the direction one is facing
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RelativeDirection {
+    Straight, Left, Right, Back,
+}
+
+impl<T: Coordinate> Cor<T> {
+    pub fn new(row: T, col: T) -> Self {
+        Self { row, col }
+    }
+
+    // None if the coordinate can't be represented by T, e.g. north of row 0 for unsigned types
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        self.checked_step_by(direction, T::ONE)
+    }
+
+    pub fn checked_step_by(self, direction: Direction, distance: T) -> Option<Self> {
+        Some(match direction {
+            Direction::N => Self { row: self.row.checked_sub(distance)?, ..self },
+            Direction::S => Self { row: self.row.checked_add(distance)?, ..self },
+            Direction::W => Self { col: self.col.checked_sub(distance)?, ..self },
+            Direction::E => Self { col: self.col.checked_add(distance)?, ..self },
+        })
+    }
+
+    // the direction to an adjacent coordinate in the same row or column
+    pub fn direction_to(self, other: Self) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|&d| self.checked_step(d) == Some(other))
+    }
+}
+
+// panics instead of wrapping around if the coordinate leaves the range of T, use checked_step to handle that
+impl<T: Coordinate> Add<Direction> for Cor<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self.checked_step(direction).unwrap_or_else(|| panic!("stepping {direction:?} from {self:?} leaves the range of the coordinates"))
+    }
+}
+
+impl<T: Coordinate> AddAssign<Direction> for Cor<T> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::N, Self::S, Self::W, Self::E];
+
+    pub fn opposite(self) -> Self {
+        self.turn(RelativeDirection::Back)
+    }
+
+    pub fn turn(self, relative_direction: RelativeDirection) -> Self {
+        match (self, relative_direction) {
+            (d, RelativeDirection::Straight) => d,
+
+            (Self::N, RelativeDirection::Left) => Self::W,
+            (Self::W, RelativeDirection::Left) => Self::S,
+            (Self::S, RelativeDirection::Left) => Self::E,
+            (Self::E, RelativeDirection::Left) => Self::N,
+
+            (Self::N, RelativeDirection::Right) => Self::E,
+            (Self::E, RelativeDirection::Right) => Self::S,
+            (Self::S, RelativeDirection::Right) => Self::W,
+            (Self::W, RelativeDirection::Right) => Self::N,
+
+            (Self::N, RelativeDirection::Back) => Self::S,
+            (Self::S, RelativeDirection::Back) => Self::N,
+            (Self::W, RelativeDirection::Back) => Self::E,
+            (Self::E, RelativeDirection::Back) => Self::W,
+        }
+    }
+
+    // how to turn when facing in this direction to face in `other` direction
+    pub fn relative(self, other: Self) -> RelativeDirection {
+        [RelativeDirection::Straight, RelativeDirection::Left, RelativeDirection::Right, RelativeDirection::Back]
+            .into_iter()
+            .find(|&rd| self.turn(rd) == other)
+            .unwrap()
+    }
+
+    // the change of row and column when stepping into this direction
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::N => (-1, 0),
+            Self::S => (1, 0),
+            Self::W => (0, -1),
+            Self::E => (0, 1),
+        }
+    }
+}
+
+impl RelativeDirection {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Straight => Self::Back,
+            Self::Back => Self::Straight,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Cor, Direction, RelativeDirection};
+
+    #[test]
+    fn test_checked_step_unsigned() {
+        let cor = Cor::<u8>::new(0, 255);
+        assert_eq!(cor.checked_step(Direction::N), None);
+        assert_eq!(cor.checked_step(Direction::E), None);
+        assert_eq!(cor.checked_step(Direction::S), Some(Cor::new(1, 255)));
+        assert_eq!(cor.checked_step(Direction::W), Some(Cor::new(0, 254)));
+    }
+
+    #[test]
+    fn test_checked_step_signed() {
+        let cor = Cor::<i16>::new(0, 0);
+        assert_eq!(cor.checked_step(Direction::N), Some(Cor::new(-1, 0)));
+        assert_eq!(cor.checked_step_by(Direction::W, 3), Some(Cor::new(0, -3)));
+        assert_eq!(cor + Direction::S + Direction::E, Cor::new(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "leaves the range")]
+    fn test_add_underflow() {
+        let _ = Cor::<usize>::new(0, 0) + Direction::N;
+    }
+
+    #[test]
+    fn test_direction_to() {
+        let cor = Cor::<usize>::new(1, 1);
+        assert_eq!(cor.direction_to(Cor::new(0, 1)), Some(Direction::N));
+        assert_eq!(cor.direction_to(Cor::new(1, 2)), Some(Direction::E));
+        assert_eq!(cor.direction_to(Cor::new(2, 2)), None);
+    }
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Direction::N.turn(RelativeDirection::Left), Direction::W);
+        assert_eq!(Direction::W.turn(RelativeDirection::Right), Direction::N);
+        assert_eq!(Direction::E.opposite(), Direction::W);
+        for d in Direction::ALL {
+            assert_eq!(d.turn(RelativeDirection::Left).turn(RelativeDirection::Right), d);
+            assert_eq!(d.turn(RelativeDirection::Left).turn(RelativeDirection::Left), d.opposite());
+        }
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(Direction::N.relative(Direction::W), RelativeDirection::Left);
+        assert_eq!(Direction::S.relative(Direction::W), RelativeDirection::Right);
+        assert_eq!(Direction::E.relative(Direction::E), RelativeDirection::Straight);
+        assert_eq!(Direction::E.relative(Direction::W), RelativeDirection::Back);
+    }
+}
@@ -0,0 +1,289 @@
+// A dense two dimensional grid as most puzzle inputs are maps with one character per tile.
+// Rows are counted from the top and columns from the left, both starting at 0.
+
+pub mod geometry;
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::parse::{self, ParseError};
+
+pub use geometry::{Cor, Direction, RelativeDirection};
+
+// a tile which is written as a single character in the puzzle input
+pub trait Tile: Sized {
+    fn from_char(symbol: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Tile> Grid<T> {
+    // fails on unknown tiles and rows of different length
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, T::from_char)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn parse_with(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut tiles = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for (row, ln) in input.lines().enumerate() {
+            let len = tiles.len();
+            for (symbol, token) in parse::chars(ln) {
+                tiles.push(tile(symbol).ok_or_else(|| ParseError::new(token, "unknown tile"))?);
+            }
+            if row == 0 {
+                cols = tiles.len();
+            }
+            if tiles.len() - len != cols {
+                return Err(ParseError::new(ln, format!("row has a different length than the first one with {cols} tiles")));
+            }
+            rows += 1;
+        }
+        Ok(Self { tiles, rows, cols })
+    }
+
+    pub fn new(rows: usize, cols: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { tiles: vec![tile; rows * cols], rows, cols }
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut tile: impl FnMut(Cor) -> T) -> Self {
+        let tiles = (0..rows).flat_map(|row| (0..cols).map(move |col| Cor { row, col })).map(&mut tile).collect();
+        Self { tiles, rows, cols }
+    }
+
+    pub fn map<U>(&self, tile: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { tiles: self.tiles.iter().map(tile).collect(), rows: self.rows, cols: self.cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, cor: Cor) -> bool {
+        cor.row < self.rows && cor.col < self.cols
+    }
+
+    pub fn get(&self, cor: Cor) -> Option<&T> {
+        self.contains(cor).then(|| &self.tiles[cor.row * self.cols + cor.col])
+    }
+
+    pub fn get_mut(&mut self, cor: Cor) -> Option<&mut T> {
+        self.contains(cor).then(|| &mut self.tiles[cor.row * self.cols + cor.col])
+    }
+
+    // the grid repeats infinitely in every direction
+    pub fn get_wrapping(&self, row: i64, col: i64) -> &T {
+        &self[self.wrap(row, col)]
+    }
+
+    pub fn wrap(&self, row: i64, col: i64) -> Cor {
+        Cor {
+            row: row.rem_euclid(self.rows as i64) as usize,
+            col: col.rem_euclid(self.cols as i64) as usize,
+        }
+    }
+
+    // None if the step leaves the grid
+    pub fn step(&self, cor: Cor, direction: Direction) -> Option<Cor> {
+        cor.checked_step(direction).filter(|&cor| self.contains(cor))
+    }
+
+    // moves `cor` by the given number of rows and columns, None if it leaves the grid
+    pub fn offset(&self, cor: Cor, rows: isize, cols: isize) -> Option<Cor> {
+        let cor = Cor {
+            row: cor.row.checked_add_signed(rows)?,
+            col: cor.col.checked_add_signed(cols)?,
+        };
+        self.contains(cor).then_some(cor)
+    }
+
+    // the neighbours above, below, left and right of `cor` inside of the grid
+    pub fn neighbours4(&self, cor: Cor) -> impl Iterator<Item = Cor> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(cor, d))
+    }
+
+    // like neighbours4 including the diagonal neighbours
+    pub fn neighbours8(&self, cor: Cor) -> impl Iterator<Item = Cor> + '_ {
+        [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]
+            .into_iter()
+            .filter_map(move |(rows, cols)| self.offset(cor, rows, cols))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.tiles[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.tiles[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.cols, "column {col} is out of bounds");
+        self.tiles.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks panics for a size of 0 which is the case for a grid without columns
+        self.tiles.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_cols(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(|col| self.col(col))
+    }
+
+    pub fn cors(&self) -> impl Iterator<Item = Cor> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Cor { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Cor, &T)> {
+        self.cors().zip(self.tiles.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Cor, &mut T)> {
+        self.cors().zip(self.tiles.iter_mut())
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.tiles.iter()
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Cor> {
+        self.iter().find(|(_, tile)| predicate(tile)).map(|(cor, _)| cor)
+    }
+}
+
+impl<T> Index<Cor> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, cor: Cor) -> &T {
+        self.get(cor).unwrap_or_else(|| panic!("{cor:?} is outside of the {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Cor> for Grid<T> {
+    fn index_mut(&mut self, cor: Cor) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(cor).unwrap_or_else(|| panic!("{cor:?} is outside of the {rows}x{cols} grid"))
+    }
+}
+
+// writes the grid in the same format it has been parsed from
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.iter_rows() {
+            for tile in row {
+                write!(f, "{}", tile.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cor, Grid, Tile};
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    enum Terrain {
+        Ash,
+        Rock,
+    }
+
+    impl Tile for Terrain {
+        fn from_char(symbol: char) -> Option<Self> {
+            match symbol {
+                '.' => Some(Self::Ash),
+                '#' => Some(Self::Rock),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Self::Ash => '.',
+                Self::Rock => '#',
+            }
+        }
+    }
+
+    const INPUT: &str = "#.#\n..#\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::<Terrain>::parse(INPUT).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Cor::new(1, 2)], Terrain::Rock);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn test_parse_unknown_tile() {
+        let input = "#.#\n.x#\n";
+        let err = Grid::<Terrain>::parse(input).unwrap_err().locate(input);
+        assert_eq!(err.token, "x");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_parse_different_lengths() {
+        let input = "#.#\n.#\n";
+        let err = Grid::<Terrain>::parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message, "row has a different length than the first one with 3 tiles");
+        assert_eq!(err.location.map(|l| l.line), Some(2));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::<Terrain>::parse(INPUT).unwrap();
+        assert_eq!(grid.get(Cor::new(1, 3)), None);
+        assert_eq!(grid.get(Cor::new(2, 0)), None);
+        assert_eq!(grid.offset(Cor::new(0, 0), -1, 0), None);
+        assert_eq!(grid.offset(Cor::new(0, 0), 1, 2), Some(Cor::new(1, 2)));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::<Terrain>::parse(INPUT).unwrap();
+        assert_eq!(grid.wrap(-1, -1), Cor::new(1, 2));
+        assert_eq!(grid.wrap(4, 7), Cor::new(0, 1));
+        assert_eq!(*grid.get_wrapping(-2, 3), Terrain::Rock);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4(Cor::new(0, 0)).collect::<Vec<_>>(), vec![Cor::new(1, 0), Cor::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Cor::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Cor::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Cor::new(2, 2)).collect::<Vec<_>>(), vec![Cor::new(1, 1), Cor::new(1, 2), Cor::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::from_fn(2, 3, |cor| cor.row * 10 + cor.col);
+        assert_eq!(grid.row(1), &[10, 11, 12]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![2, 12]);
+        assert_eq!(grid.col(0).rev().copied().collect::<Vec<_>>(), vec![10, 0]);
+        assert_eq!(grid.iter_cols().map(|col| col.sum::<usize>()).collect::<Vec<_>>(), vec![10, 12, 14]);
+        assert_eq!(grid.position(|&tile| tile == 11), Some(Cor::new(1, 1)));
+    }
+}
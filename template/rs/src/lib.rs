@@ -0,0 +1,32 @@
+use common::parse::ParseError;
+
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle { lines: input.lines().map(str::to_string).collect() })
+}
+
+pub fn part1(puzzle: &Puzzle) -> usize {
+    puzzle.lines.len()
+}
+
+pub fn part2(puzzle: &Puzzle) -> usize {
+    puzzle.lines.len()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, part1};
+
+    // fill in the answer the puzzle text gives for the example, save the example as `exp` and remove the ignore
+    #[test]
+    #[ignore = "the answer of the example is not filled in yet"]
+    fn test_part1_example() {
+        let puzzle = parse(&common::input::read_example({{day}}, "exp")).unwrap();
+        assert_eq!(part1(&puzzle), 0);
+    }
+}
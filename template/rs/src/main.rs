@@ -0,0 +1,7 @@
+fn main() {
+    let (part, puzzle) = common::input::parse_part_from_args({{day}}, rs_{{day}}::parse);
+    match part {
+        1 => println!("result = {}", rs_{{day}}::part1(&puzzle)),
+        _ => println!("result = {}", rs_{{day}}::part2(&puzzle)),
+    }
+}